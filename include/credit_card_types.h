@@ -0,0 +1,138 @@
+#ifndef CREDIT_CARD_TYPES_H
+#define CREDIT_CARD_TYPES_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit by hand. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// The call succeeded.
+#define CCT_OK 0
+
+// A required pointer argument was null.
+#define CCT_ERROR_NULL_POINTER -1
+
+// A string argument was not valid UTF-8.
+#define CCT_ERROR_INVALID_UTF8 -2
+
+// The card number contained something other than digits.
+#define CCT_ERROR_INVALID_CARD_NUMBER -3
+
+// The output buffer was too small, `out_count` holds the number of matches.
+#define CCT_ERROR_BUFFER_TOO_SMALL -4
+
+// The card type to insert was invalid, or did not fit in a [`CctCardType`].
+#define CCT_ERROR_INVALID_CARD_TYPE -5
+
+// Size of [`CctCardType::brand`], including the terminating NUL.
+#define CCT_BRAND_LEN 32
+
+// Size of [`CctCardType::nice_type`], including the terminating NUL.
+#define CCT_NICE_TYPE_LEN 64
+
+// Size of [`CctCardType::code_name`], including the terminating NUL.
+#define CCT_CODE_NAME_LEN 16
+
+// Capacity of [`CctCardType::gaps`].
+#define CCT_MAX_GAPS 8
+
+// Capacity of [`CctCardType::lengths`].
+#define CCT_MAX_LENGTHS 16
+
+// An opaque pool of card types, created with `cct_pool_new` and freed with `cct_pool_free`.
+typedef struct CctPool CctPool;
+
+// A pattern of a card type to insert: a prefix if `max` is null, otherwise an inclusive range of prefixes.
+typedef struct CctPattern {
+  const char *min;
+  const char *max;
+} CctPattern;
+
+// A card type to insert with `cct_pool_insert_card_type`, the data is copied into the pool.
+typedef struct CctCardTypeSpec {
+  const char *brand;
+  const char *nice_type;
+  const struct CctPattern *patterns;
+  size_t patterns_len;
+  const uint32_t *gaps;
+  size_t gaps_len;
+  const uint32_t *lengths;
+  size_t lengths_len;
+  const char *code_name;
+  uint32_t code_size;
+} CctCardTypeSpec;
+
+// A detected card type, with every field stored inline.
+typedef struct CctCardType {
+  // The brand identifier, e.g. `"american-express"`, NUL terminated.
+  char brand[CCT_BRAND_LEN];
+  // The display name, e.g. `"American Express"`, NUL terminated.
+  char nice_type[CCT_NICE_TYPE_LEN];
+  // The name of the security code, e.g. `"CID"`, NUL terminated.
+  char code_name[CCT_CODE_NAME_LEN];
+  // The number of digits of the security code.
+  uint32_t code_size;
+  // How specific the matched pattern is.
+  uint32_t match_strength;
+  // Positions of the spaces in a formatted number, the first `gaps_len` entries are set.
+  uint32_t gaps[CCT_MAX_GAPS];
+  size_t gaps_len;
+  // Allowed lengths of a full number, the first `lengths_len` entries are set.
+  uint32_t lengths[CCT_MAX_LENGTHS];
+  size_t lengths_len;
+} CctCardType;
+
+// A list of detected card types allocated by `cct_pool_detect_alloc`, freed with `cct_card_type_list_free`.
+typedef struct CctCardTypeList {
+  struct CctCardType *items;
+  size_t len;
+} CctCardTypeList;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a pool with the default card types.
+struct CctPool *cct_pool_new(void);
+
+// Creates a pool without card types.
+struct CctPool *cct_pool_new_empty(void);
+
+// Frees a pool, null is ignored.
+void cct_pool_free(struct CctPool *pool);
+
+// Inserts a card type, replacing the card type with the same brand. The data of `spec` is copied, and the copy of a
+// replaced card type is freed.
+int32_t cct_pool_insert_card_type(struct CctPool *pool, const struct CctCardTypeSpec *spec);
+
+// Removes the card type of a brand, unknown brands are ignored.
+int32_t cct_pool_remove_card_type(struct CctPool *pool, const char *brand);
+
+// Detects the card types of a full or partial card number.
+//
+// Writes up to `out_len` matches to `out` and the total number of matches to `out_count`. Returns
+// `CCT_ERROR_BUFFER_TOO_SMALL` if there are more than `out_len` matches, `out` can be null if `out_len` is 0.
+int32_t cct_pool_detect(const struct CctPool *pool,
+                        const char *card_number,
+                        struct CctCardType *out,
+                        size_t out_len,
+                        size_t *out_count);
+
+// Detects the card types of a full or partial card number into a list allocated by the library.
+//
+// On success `out` must be freed with `cct_card_type_list_free`.
+int32_t cct_pool_detect_alloc(const struct CctPool *pool,
+                              const char *card_number,
+                              struct CctCardTypeList *out);
+
+// Frees a list returned by `cct_pool_detect_alloc`, the list is reset to empty.
+void cct_card_type_list_free(struct CctCardTypeList *list);
+
+// Returns a static, NUL terminated description of a status code.
+const char *cct_status_message(int32_t status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CREDIT_CARD_TYPES_H */
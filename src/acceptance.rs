@@ -0,0 +1,226 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    card_brand::CardBrand, card_range::FundingType, card_types::CreditCardPool,
+    errors::CardTypeError,
+};
+
+/// The brands and funding types a merchant accepts.
+///
+/// A policy answers "is this card accepted here" on top of [`CreditCardPool::get_credit_card_type`]. It does not
+/// validate the number, use [`CreditCardPool::validate_card_number`] for the length and checksum.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{AcceptancePolicy, CardBrand, CreditCardPool, FundingType};
+///
+/// let pool = CreditCardPool::new();
+/// let policy = AcceptancePolicy::new([CardBrand::Visa, CardBrand::Mastercard, CardBrand::Discover])
+///     .without_funding(FundingType::Prepaid);
+///
+/// assert!(policy.check(&pool, "4111111111111111").unwrap().is_accepted());
+///
+/// let acceptance = policy.check(&pool, "378282246310005").unwrap();
+/// assert_eq!(
+///     acceptance.rejection().unwrap().to_string(),
+///     "American Express cards are not accepted."
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AcceptancePolicy {
+    brands: Vec<CardBrand>,
+    funding: Vec<FundingType>,
+    co_badged_routing: bool,
+}
+
+impl AcceptancePolicy {
+    /// Creates a policy accepting the given brands, with every funding type and without co-badged routing.
+    #[must_use]
+    pub fn new(brands: impl IntoIterator<Item = CardBrand>) -> AcceptancePolicy {
+        AcceptancePolicy {
+            brands: brands.into_iter().collect(),
+            funding: [
+                FundingType::Credit,
+                FundingType::Debit,
+                FundingType::Prepaid,
+                FundingType::Charge,
+                FundingType::Unknown,
+            ]
+            .to_vec(),
+            co_badged_routing: false,
+        }
+    }
+
+    /// Only accepts the given funding types.
+    ///
+    /// Include [`FundingType::Unknown`] to accept the cards whose funding type is not known, most card numbers have
+    /// no funding information.
+    #[must_use]
+    pub fn with_funding(
+        mut self,
+        funding: impl IntoIterator<Item = FundingType>,
+    ) -> AcceptancePolicy {
+        self.funding = funding.into_iter().collect();
+        self
+    }
+
+    /// Stops accepting a funding type.
+    #[must_use]
+    pub fn without_funding(mut self, funding: FundingType) -> AcceptancePolicy {
+        self.funding.retain(|allowed| *allowed != funding);
+        self
+    }
+
+    /// Accepts co-badged cards whose primary brand is not accepted, by routing them to an accepted co-badged network.
+    #[must_use]
+    pub fn with_co_badged_routing(mut self, co_badged_routing: bool) -> AcceptancePolicy {
+        self.co_badged_routing = co_badged_routing;
+        self
+    }
+
+    /// Returns the accepted brands.
+    #[must_use]
+    pub fn brands(&self) -> &[CardBrand] {
+        &self.brands
+    }
+
+    /// Returns the accepted funding types.
+    #[must_use]
+    pub fn funding(&self) -> &[FundingType] {
+        &self.funding
+    }
+
+    /// Returns `true` if co-badged cards may be routed to an accepted co-badged network.
+    #[must_use]
+    pub fn co_badged_routing(&self) -> bool {
+        self.co_badged_routing
+    }
+
+    /// Checks whether a full or partial card number is accepted.
+    ///
+    /// The number must match a single card type of the pool. When its brand is not accepted, a co-badged card is
+    /// routed to the first accepted network it carries if routing is enabled.
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn check(
+        &self,
+        pool: &CreditCardPool,
+        card_number: impl AsRef<str>,
+    ) -> Result<Acceptance, CardTypeError> {
+        let card_number = card_number.as_ref();
+
+        let card_type = match pool.get_credit_card_type(card_number)?.as_slice() {
+            [] => return Ok(Acceptance::Rejected(Rejection::Unknown)),
+            [card_type] => card_type.clone(),
+            _ => return Ok(Acceptance::Rejected(Rejection::Ambiguous)),
+        };
+        let co_badged = card_type.co_badged(card_number)?;
+
+        let network = if self.brands.contains(&card_type.type_) {
+            Some(card_type.type_)
+        } else if self.co_badged_routing {
+            co_badged
+                .iter()
+                .copied()
+                .find(|brand| self.brands.contains(brand))
+        } else {
+            None
+        };
+        let Some(network) = network else {
+            return Ok(Acceptance::Rejected(Rejection::UnsupportedBrand {
+                brand: card_type.type_,
+                nice_type: card_type.nice_type,
+            }));
+        };
+
+        let funding = card_type.funding(card_number)?;
+        if !self.funding.contains(&funding) {
+            return Ok(Acceptance::Rejected(Rejection::UnsupportedFunding(funding)));
+        }
+
+        Ok(Acceptance::Accepted {
+            network,
+            funding,
+            routed: network != card_type.type_,
+        })
+    }
+}
+
+/// The result of [`AcceptancePolicy::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Acceptance {
+    /// The card is accepted.
+    Accepted {
+        /// The network to charge the card on.
+        network: CardBrand,
+        /// The funding type of the card.
+        funding: FundingType,
+        /// `true` if the card is routed to a co-badged network instead of its primary brand.
+        routed: bool,
+    },
+    /// The card is not accepted.
+    Rejected(Rejection),
+}
+
+impl Acceptance {
+    /// Returns `true` if the card is accepted.
+    #[must_use]
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Acceptance::Accepted { .. })
+    }
+
+    /// Returns the reason the card is not accepted.
+    #[must_use]
+    pub fn rejection(&self) -> Option<Rejection> {
+        match self {
+            Acceptance::Accepted { .. } => None,
+            Acceptance::Rejected(rejection) => Some(*rejection),
+        }
+    }
+}
+
+/// Why a card is not accepted, its [`Display`](fmt::Display) is a message for the shopper.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rejection {
+    /// No card type matches the number.
+    Unknown,
+    /// More than one card type matches the number, more digits are needed.
+    Ambiguous,
+    /// The brand of the card is not accepted.
+    UnsupportedBrand {
+        brand: CardBrand,
+        /// The display name of the brand, e.g. `"American Express"`.
+        nice_type: &'static str,
+    },
+    /// The funding type of the card is not accepted.
+    UnsupportedFunding(FundingType),
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rejection::Unknown => write!(f, "This card number is not recognized."),
+            Rejection::Ambiguous => write!(f, "Enter the full card number."),
+            Rejection::UnsupportedBrand { nice_type, .. } => {
+                write!(f, "{nice_type} cards are not accepted.")
+            }
+            Rejection::UnsupportedFunding(FundingType::Unknown) => {
+                write!(f, "This card type is not accepted.")
+            }
+            Rejection::UnsupportedFunding(funding) => {
+                let funding = funding.as_str();
+                let (first, rest) = funding.split_at(1);
+                write!(
+                    f,
+                    "{}{rest} cards are not accepted.",
+                    first.to_ascii_uppercase()
+                )
+            }
+        }
+    }
+}
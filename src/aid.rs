@@ -0,0 +1,268 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+use crate::card_brand::CardBrand;
+#[cfg(feature = "alloc")]
+use crate::card_types::{CreditCardPool, CreditCardType};
+
+/// The length of a Registered Application Provider Identifier, the first part of an AID.
+const RID_LENGTH: usize = 5;
+
+/// An EMV Application Identifier, or a prefix of one, with the network and product it belongs to.
+///
+/// An AID is a 5 bytes RID, which identifies the network, followed by a PIX of up to 11 bytes, which identifies the
+/// product. An entry can be a whole AID like `A0000000031010`, or a RID alone to match every product of a network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AidEntry {
+    /// The RID followed by the PIX, or a part of it.
+    pub prefix: &'static [u8],
+    /// The network.
+    pub brand: CardBrand,
+    /// The product name, e.g. `"Visa Electron"`.
+    pub product: &'static str,
+}
+
+impl AidEntry {
+    /// Creates an entry.
+    #[must_use]
+    pub const fn new(prefix: &'static [u8], brand: CardBrand, product: &'static str) -> AidEntry {
+        AidEntry {
+            prefix,
+            brand,
+            product,
+        }
+    }
+
+    /// Returns the RID, the first 5 bytes of the prefix.
+    #[must_use]
+    pub fn rid(&self) -> &'static [u8] {
+        &self.prefix[..RID_LENGTH.min(self.prefix.len())]
+    }
+
+    /// Returns the PIX, the bytes of the prefix after the RID.
+    #[must_use]
+    pub fn pix(&self) -> &'static [u8] {
+        &self.prefix[RID_LENGTH.min(self.prefix.len())..]
+    }
+}
+
+/// The built-in AIDs, sorted by prefix.
+///
+/// Each network has an entry for its RID, and entries for the AIDs of its products.
+pub const DEFAULT_AIDS: &[AidEntry] = &[
+    AidEntry::new(&[0xA0, 0x00, 0x00, 0x00, 0x03], CardBrand::Visa, "Visa"),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x03, 0x10, 0x10],
+        CardBrand::Visa,
+        "Visa Credit/Debit",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x03, 0x20, 0x10],
+        CardBrand::Visa,
+        "Visa Electron",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x03, 0x20, 0x20],
+        CardBrand::Visa,
+        "V PAY",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x03, 0x80, 0x10],
+        CardBrand::Visa,
+        "Visa Plus",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x04],
+        CardBrand::Mastercard,
+        "Mastercard",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x04, 0x10, 0x10],
+        CardBrand::Mastercard,
+        "Mastercard Credit/Debit",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x04, 0x30, 0x60],
+        CardBrand::Maestro,
+        "Maestro",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x04, 0x60, 0x00],
+        CardBrand::Mastercard,
+        "Cirrus",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x25],
+        CardBrand::AmericanExpress,
+        "American Express",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x00, 0x42],
+        CardBrand::CartesBancaires,
+        "CB",
+    ),
+    AidEntry::new(&[0xA0, 0x00, 0x00, 0x00, 0x65], CardBrand::Jcb, "JCB"),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x01, 0x21],
+        CardBrand::Dankort,
+        "Dankort",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x01, 0x52],
+        CardBrand::Discover,
+        "Discover",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x01, 0x52, 0x30, 0x10],
+        CardBrand::Discover,
+        "Discover/Diners Club",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x03, 0x24],
+        CardBrand::Discover,
+        "Discover ZIP",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x03, 0x33],
+        CardBrand::UnionPay,
+        "UnionPay",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x03, 0x33, 0x01, 0x01, 0x01],
+        CardBrand::UnionPay,
+        "UnionPay Debit",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x03, 0x33, 0x01, 0x01, 0x02],
+        CardBrand::UnionPay,
+        "UnionPay Credit",
+    ),
+    AidEntry::new(&[0xA0, 0x00, 0x00, 0x03, 0x71], CardBrand::Verve, "Verve"),
+    AidEntry::new(&[0xA0, 0x00, 0x00, 0x03, 0x84], CardBrand::Eftpos, "eftpos"),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x03, 0x84, 0x10],
+        CardBrand::Eftpos,
+        "eftpos Savings",
+    ),
+    AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x03, 0x84, 0x20],
+        CardBrand::Eftpos,
+        "eftpos Cheque",
+    ),
+    AidEntry::new(&[0xA0, 0x00, 0x00, 0x04, 0x94], CardBrand::Elo, "Elo"),
+    AidEntry::new(&[0xA0, 0x00, 0x00, 0x05, 0x24], CardBrand::RuPay, "RuPay"),
+    AidEntry::new(&[0xA0, 0x00, 0x00, 0x06, 0x58], CardBrand::Mir, "Mir"),
+    AidEntry::new(&[0xA0, 0x00, 0x00, 0x06, 0x72], CardBrand::Troy, "Troy"),
+    AidEntry::new(&[0xA0, 0x00, 0x00, 0x07, 0x27], CardBrand::Napas, "NAPAS"),
+    AidEntry::new(
+        &[0xD0, 0x56, 0x00, 0x06, 0x66],
+        CardBrand::Bancontact,
+        "Bancontact",
+    ),
+];
+
+/// Maps EMV Application Identifiers to networks, for card-present flows where the network is known by AID rather
+/// than by card number.
+///
+/// The registry is a slice of [`AidEntry`] sorted by prefix, an AID is matched by the longest prefix. Like
+/// [`CreditCardPool`], the default entries are borrowed from [`DEFAULT_AIDS`] and only copied when the registry is
+/// modified.
+///
+/// Requires the `alloc` feature.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{AidEntry, AidRegistry, CardBrand, CreditCardPool};
+///
+/// let mut registry = AidRegistry::new();
+/// registry.insert_aid(AidEntry::new(
+///     &[0xA0, 0x00, 0x00, 0x00, 0x03, 0x99, 0x99],
+///     CardBrand::Visa,
+///     "Visa Fleet",
+/// ));
+///
+/// let entry = registry.lookup(&[0xA0, 0x00, 0x00, 0x00, 0x03, 0x20, 0x10]).unwrap();
+/// assert_eq!(entry.product, "Visa Electron");
+///
+/// let pool = CreditCardPool::new();
+/// let card_type = registry.card_type(&[0xA0, 0x00, 0x00, 0x00, 0x03, 0x99, 0x99], &pool).unwrap();
+/// assert_eq!(card_type.gaps, [4, 8, 12]);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AidRegistry(Cow<'static, [AidEntry]>);
+
+#[cfg(feature = "alloc")]
+impl AidRegistry {
+    /// Creates a registry with the default AIDs.
+    #[must_use]
+    pub const fn new() -> AidRegistry {
+        AidRegistry(Cow::Borrowed(DEFAULT_AIDS))
+    }
+
+    /// Creates an empty registry.
+    #[must_use]
+    pub const fn new_empty() -> AidRegistry {
+        AidRegistry(Cow::Borrowed(&[]))
+    }
+
+    /// Inserts an entry, replacing the entry with the same prefix.
+    pub fn insert_aid(&mut self, entry: AidEntry) {
+        match self.find(entry.prefix) {
+            Ok(index) => self.0.to_mut()[index] = entry,
+            Err(index) => self.0.to_mut().insert(index, entry),
+        }
+    }
+
+    /// Removes the entry with the given prefix.
+    pub fn remove_aid(&mut self, prefix: &[u8]) {
+        if let Ok(index) = self.find(prefix) {
+            self.0.to_mut().remove(index);
+        }
+    }
+
+    /// Returns the entry with the longest prefix of `aid`, `None` if no entry matches.
+    #[must_use]
+    pub fn lookup(&self, aid: &[u8]) -> Option<&AidEntry> {
+        self.0
+            .iter()
+            .filter(|entry| aid.starts_with(entry.prefix))
+            .max_by_key(|entry| entry.prefix.len())
+    }
+
+    /// Returns the network of `aid`.
+    #[must_use]
+    pub fn brand(&self, aid: &[u8]) -> Option<CardBrand> {
+        self.lookup(aid).map(|entry| entry.brand)
+    }
+
+    /// Returns the card type of the network of `aid` in `pool`, `None` if the AID is unknown or the pool has no card
+    /// type for its network.
+    #[must_use]
+    pub fn card_type(&self, aid: &[u8], pool: &CreditCardPool) -> Option<CreditCardType> {
+        let brand = self.brand(aid)?;
+
+        pool.card_types()
+            .iter()
+            .find(|card_type| card_type.type_ == brand)
+            .cloned()
+    }
+
+    /// Returns all entries, sorted by prefix.
+    #[must_use]
+    pub fn aids(&self) -> &[AidEntry] {
+        &self.0
+    }
+
+    fn find(&self, prefix: &[u8]) -> Result<usize, usize> {
+        self.0.binary_search_by(|entry| entry.prefix.cmp(prefix))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for AidRegistry {
+    fn default() -> Self {
+        AidRegistry::new()
+    }
+}
@@ -0,0 +1,683 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use std::io::{self, BufRead, Write};
+
+use crate::{
+    card_brand::CardBrand, errors::BatchError, formatting::mask_card_number, CreditCardPool,
+};
+
+/// The names of the columns (or JSON keys) added to every row by [`BatchProcessor`].
+const OUTPUT_COLUMNS: [&str; 4] = ["brand", "valid", "masked", "error"];
+
+/// The maximum size of a CSV record, which can span several lines, or of a JSON line. Longer ones are reported as
+/// malformed, so a missing line break doesn't make the whole input held in memory.
+const MAX_RECORD_LEN: usize = 64 * 1024;
+
+/// The input and output format of a [`BatchProcessor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BatchFormat {
+    /// Comma separated values with a header row.
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+}
+
+/// Counters of a finished batch run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BatchSummary {
+    /// Number of rows read, not counting the CSV header.
+    pub rows: usize,
+    /// Rows with a card number that passed validation.
+    pub valid: usize,
+    /// Rows with a card number that failed validation.
+    pub invalid: usize,
+    /// Masked card numbers, which can be detected but not validated.
+    pub masked: usize,
+    /// Rows that could not be processed, the reason is written to their `error` column.
+    pub errors: usize,
+}
+
+/// Reads card numbers from CSV or JSON Lines, detects and validates them, and writes the rows back enriched with
+/// `brand`, `valid`, `masked` and `error` columns.
+///
+/// Rows are processed one at a time, so memory usage does not grow with the size of the input. A row that can not be
+/// processed does not abort the run, the reason is written to its `error` column instead.
+///
+/// Card numbers may contain spaces and dashes. Masked numbers (using `*`, `x` or `X`) are detected by the digits
+/// before the mask, and their `valid` column is left empty.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{BatchFormat, BatchProcessor, CreditCardPool};
+///
+/// let pool = CreditCardPool::new();
+/// let processor = BatchProcessor::new(&pool, BatchFormat::Csv, "pan");
+///
+/// let input = "id,pan\n1,4111111111111111\n2,411111******1111\n";
+/// let mut output = Vec::new();
+/// let summary = processor.process(input.as_bytes(), &mut output).unwrap();
+///
+/// assert_eq!(summary.valid, 1);
+/// assert_eq!(summary.masked, 1);
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "id,pan,brand,valid,masked,error\n\
+///      1,4111111111111111,visa,true,************1111,\n\
+///      2,411111******1111,visa,,************1111,\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchProcessor<'a> {
+    pool: &'a CreditCardPool,
+    format: BatchFormat,
+    column: String,
+    delimiter: u8,
+}
+
+impl<'a> BatchProcessor<'a> {
+    /// Creates a processor that reads the card number from `column`, a CSV header name or a top level JSON key.
+    #[must_use]
+    pub fn new(pool: &'a CreditCardPool, format: BatchFormat, column: impl Into<String>) -> Self {
+        BatchProcessor {
+            pool,
+            format,
+            column: column.into(),
+            delimiter: b',',
+        }
+    }
+
+    /// Sets the CSV delimiter, `,` by default.
+    #[must_use]
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Processes all rows of `reader` and writes the enriched rows to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading or writing fails, or if the CSV header does not contain the card number column.
+    pub fn process(
+        &self,
+        mut reader: impl BufRead,
+        mut writer: impl Write,
+    ) -> Result<BatchSummary, BatchError> {
+        match self.format {
+            BatchFormat::Csv => self.process_csv(&mut reader, &mut writer),
+            BatchFormat::JsonLines => self.process_json_lines(&mut reader, &mut writer),
+        }
+    }
+
+    fn process_csv(
+        &self,
+        reader: &mut impl BufRead,
+        writer: &mut impl Write,
+    ) -> Result<BatchSummary, BatchError> {
+        let mut summary = BatchSummary::default();
+        let mut record = String::new();
+
+        let Some(header) = read_csv_record(reader, &mut record, self.delimiter)? else {
+            return Ok(summary);
+        };
+        let header = header.map_err(|_| BatchError::MissingColumn(self.column.clone()))?;
+        let column = header
+            .iter()
+            .position(|name| *name == self.column)
+            .ok_or_else(|| BatchError::MissingColumn(self.column.clone()))?;
+
+        let header_len = header.len();
+        let mut fields = header;
+        fields.extend(OUTPUT_COLUMNS.iter().map(ToString::to_string));
+        write_csv_record(writer, &fields, self.delimiter)?;
+
+        while let Some(fields) = read_csv_record(reader, &mut record, self.delimiter)? {
+            summary.rows += 1;
+
+            let (mut fields, enriched) = match fields {
+                Ok(fields) => {
+                    let enriched = match fields.get(column) {
+                        Some(card_number) => self.enrich(card_number),
+                        None => Enriched::error("missing card number column".to_string()),
+                    };
+                    (fields, enriched)
+                }
+                Err(error) => (vec![String::new(); header_len], Enriched::error(error)),
+            };
+            enriched.count(&mut summary);
+
+            fields.resize(fields.len().max(header_len), String::new());
+            fields.extend(enriched.into_fields());
+            write_csv_record(writer, &fields, self.delimiter)?;
+        }
+
+        Ok(summary)
+    }
+
+    fn process_json_lines(
+        &self,
+        reader: &mut impl BufRead,
+        writer: &mut impl Write,
+    ) -> Result<BatchSummary, BatchError> {
+        let mut summary = BatchSummary::default();
+        let mut line = String::new();
+
+        loop {
+            line.clear();
+            let object = match read_line(reader, &mut line, MAX_RECORD_LEN)? {
+                Line::End => break,
+                Line::TooLong => {
+                    summary.rows += 1;
+                    summary.errors += 1;
+                    writeln!(
+                        writer,
+                        "{{\"brand\":null,\"valid\":null,\"masked\":null,\"error\":{}}}",
+                        json_string("line too long")
+                    )?;
+                    continue;
+                }
+                Line::Read => line.trim(),
+            };
+            if object.is_empty() {
+                continue;
+            }
+            summary.rows += 1;
+
+            let (object, enriched) = match json_field(object, &self.column) {
+                Ok(Some(card_number)) => (object, self.enrich(&card_number)),
+                Ok(None) => (
+                    object,
+                    Enriched::error("missing card number column".to_string()),
+                ),
+                Err(error) => ("{}", Enriched::error(error.to_string())),
+            };
+            enriched.count(&mut summary);
+
+            let body = object[1..object.len() - 1].trim();
+            writer.write_all(b"{")?;
+            if !body.is_empty() {
+                writer.write_all(body.as_bytes())?;
+                writer.write_all(b",")?;
+            }
+            for (index, (name, value)) in OUTPUT_COLUMNS
+                .iter()
+                .zip(enriched.into_json_values())
+                .enumerate()
+            {
+                if index > 0 {
+                    writer.write_all(b",")?;
+                }
+                write!(writer, "\"{name}\":{value}")?;
+            }
+            writer.write_all(b"}\n")?;
+        }
+
+        Ok(summary)
+    }
+
+    fn enrich(&self, card_number: &str) -> Enriched {
+        let card_number = card_number
+            .trim()
+            .chars()
+            .filter(|character| !matches!(character, ' ' | '-'))
+            .collect::<String>();
+
+        let mask_start = card_number.find(['*', 'x', 'X']);
+        let digits = &card_number[..mask_start.unwrap_or(card_number.len())];
+        let is_masked = mask_start.is_some();
+
+        if card_number.is_empty()
+            || !digits.bytes().all(|byte| byte.is_ascii_digit())
+            || (is_masked
+                && !card_number[digits.len()..]
+                    .bytes()
+                    .all(|byte| byte.is_ascii_digit() || matches!(byte, b'*' | b'x' | b'X')))
+        {
+            return Enriched::error("not a card number".to_string());
+        }
+
+        // Masked digits stay masked, even among the last four.
+        let masked = if is_masked {
+            let hidden = card_number.len().saturating_sub(4);
+            "*".repeat(hidden) + &card_number[hidden..]
+        } else {
+            mask_card_number(&card_number)
+        };
+
+        if is_masked {
+            // Every card type matches an empty number, a fully masked one has an unknown brand.
+            if digits.is_empty() {
+                return Enriched {
+                    brands: Vec::new(),
+                    valid: None,
+                    masked,
+                    error: None,
+                };
+            }
+            return match self.pool.get_credit_card_type(digits) {
+                Ok(card_types) => Enriched {
+                    brands: card_types.iter().map(|card_type| card_type.type_).collect(),
+                    valid: None,
+                    masked,
+                    error: None,
+                },
+                Err(error) => Enriched::error(error.to_string()),
+            };
+        }
+
+        match self.pool.validate_card_number(&card_number) {
+            Ok(validation) => Enriched {
+                brands: if let Some(card_type) = &validation.card_type {
+                    vec![card_type.type_]
+                } else {
+                    self.pool
+                        .get_credit_card_type(&card_number)
+                        .map(|card_types| {
+                            card_types.iter().map(|card_type| card_type.type_).collect()
+                        })
+                        .unwrap_or_default()
+                },
+                valid: Some(validation.is_valid()),
+                masked,
+                error: None,
+            },
+            Err(error) => Enriched::error(error.to_string()),
+        }
+    }
+}
+
+/// The values added to a row.
+struct Enriched {
+    brands: Vec<CardBrand>,
+    valid: Option<bool>,
+    masked: String,
+    error: Option<String>,
+}
+
+impl Enriched {
+    fn error(error: String) -> Self {
+        Enriched {
+            brands: Vec::new(),
+            valid: None,
+            masked: String::new(),
+            error: Some(error),
+        }
+    }
+
+    fn count(&self, summary: &mut BatchSummary) {
+        match (self.valid, &self.error) {
+            (_, Some(_)) => summary.errors += 1,
+            (Some(true), None) => summary.valid += 1,
+            (Some(false), None) => summary.invalid += 1,
+            (None, None) => summary.masked += 1,
+        }
+    }
+
+    fn brand(&self) -> String {
+        self.brands
+            .iter()
+            .map(CardBrand::as_str)
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+
+    fn into_fields(self) -> [String; 4] {
+        [
+            self.brand(),
+            self.valid
+                .map(|valid| valid.to_string())
+                .unwrap_or_default(),
+            self.masked,
+            self.error.unwrap_or_default(),
+        ]
+    }
+
+    fn into_json_values(self) -> [String; 4] {
+        let brand = if self.brands.is_empty() {
+            "null".to_string()
+        } else {
+            json_string(&self.brand())
+        };
+        [
+            brand,
+            self.valid
+                .map_or_else(|| "null".to_string(), |valid| valid.to_string()),
+            if self.masked.is_empty() {
+                "null".to_string()
+            } else {
+                json_string(&self.masked)
+            },
+            self.error
+                .map_or_else(|| "null".to_string(), |error| json_string(&error)),
+        ]
+    }
+}
+
+/// Reads one CSV record into `record`, which may span several lines if a quoted field contains line breaks. Blank
+/// lines are skipped.
+///
+/// Returns `Ok(None)` at the end of the input, and `Ok(Some(Err(_)))` if the record is malformed.
+#[allow(clippy::type_complexity)]
+fn read_csv_record(
+    reader: &mut impl BufRead,
+    record: &mut String,
+    delimiter: u8,
+) -> Result<Option<Result<Vec<String>, String>>, BatchError> {
+    loop {
+        record.clear();
+
+        loop {
+            let room = MAX_RECORD_LEN - record.len();
+            match read_line(reader, record, room)? {
+                Line::End => break,
+                Line::TooLong => return Ok(Some(Err("record too long".to_string()))),
+                Line::Read => {}
+            }
+            // A record is complete once all quotes are closed, a stray quote must not swallow the rest of the input.
+            if record
+                .bytes()
+                .filter(|byte| *byte == b'"')
+                .count()
+                .is_multiple_of(2)
+            {
+                break;
+            }
+        }
+
+        if record.is_empty() {
+            return Ok(None);
+        }
+        let line = record.trim_end_matches(['\r', '\n']);
+        if !line.is_empty() {
+            return Ok(Some(parse_csv_record(line, delimiter)));
+        }
+    }
+}
+
+/// The result of [`read_line`].
+enum Line {
+    /// A line was appended.
+    Read,
+    /// The line was longer than the limit, it was skipped.
+    TooLong,
+    /// The end of the input.
+    End,
+}
+
+/// Appends the next line to `buf` like [`BufRead::read_line`], but skips lines longer than `limit` bytes instead of
+/// holding them in memory.
+fn read_line(
+    reader: &mut impl BufRead,
+    buf: &mut String,
+    limit: usize,
+) -> Result<Line, BatchError> {
+    let mut line = Vec::new();
+    let mut is_too_long = false;
+
+    loop {
+        let available = match reader.fill_buf() {
+            Ok(available) => available,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error.into()),
+        };
+        if available.is_empty() {
+            if line.is_empty() && !is_too_long {
+                return Ok(Line::End);
+            }
+            break;
+        }
+
+        let end = available.iter().position(|byte| *byte == b'\n');
+        let chunk = &available[..end.map_or(available.len(), |end| end + 1)];
+        if line.len() + chunk.len() > limit {
+            is_too_long = true;
+            line.clear();
+        } else if !is_too_long {
+            line.extend_from_slice(chunk);
+        }
+        let consumed = chunk.len();
+        reader.consume(consumed);
+
+        if end.is_some() {
+            break;
+        }
+    }
+
+    if is_too_long {
+        return Ok(Line::TooLong);
+    }
+    let line = String::from_utf8(line).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "stream did not contain valid UTF-8",
+        )
+    })?;
+    buf.push_str(&line);
+    Ok(Line::Read)
+}
+
+fn parse_csv_record(record: &str, delimiter: u8) -> Result<Vec<String>, String> {
+    let delimiter = char::from(delimiter);
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = record.chars().peekable();
+    let mut in_quotes = false;
+    let mut was_quoted = false;
+
+    while let Some(character) = chars.next() {
+        match character {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() && !was_quoted => {
+                in_quotes = true;
+                was_quoted = true;
+            }
+            character if character == delimiter && !in_quotes => {
+                fields.push(std::mem::take(&mut field));
+                was_quoted = false;
+            }
+            _ if was_quoted && !in_quotes => {
+                return Err("unexpected character after closing quote".to_string());
+            }
+            character => field.push(character),
+        }
+    }
+
+    if in_quotes {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field);
+
+    Ok(fields)
+}
+
+fn write_csv_record(
+    writer: &mut impl Write,
+    fields: &[String],
+    delimiter: u8,
+) -> Result<(), BatchError> {
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            writer.write_all(&[delimiter])?;
+        }
+        if field
+            .bytes()
+            .any(|byte| matches!(byte, b'"' | b'\n' | b'\r') || byte == delimiter)
+        {
+            write!(writer, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            writer.write_all(field.as_bytes())?;
+        }
+    }
+    writer.write_all(b"\n")?;
+
+    Ok(())
+}
+
+/// Returns the value of a top level `key` of a JSON object, strings are unescaped and other values are returned as
+/// written.
+fn json_field(object: &str, key: &str) -> Result<Option<String>, &'static str> {
+    let mut parser = JsonParser {
+        bytes: object.as_bytes(),
+        position: 0,
+    };
+    let mut found = None;
+
+    parser.expect(b'{')?;
+    if parser.peek() == Some(b'}') {
+        parser.position += 1;
+    } else {
+        loop {
+            let name = parser.string()?;
+            parser.expect(b':')?;
+            parser.skip_whitespace();
+            let value = if parser.peek() == Some(b'"') {
+                Some(parser.string()?)
+            } else {
+                let start = parser.position;
+                parser.skip_value()?;
+                Some(object[start..parser.position].trim().to_string())
+            };
+            if name == key && found.is_none() {
+                found = value;
+            }
+
+            parser.skip_whitespace();
+            match parser.next() {
+                Some(b',') => parser.skip_whitespace(),
+                Some(b'}') => break,
+                _ => return Err("invalid JSON object"),
+            }
+        }
+    }
+
+    parser.skip_whitespace();
+    if parser.position != object.len() {
+        return Err("invalid JSON object");
+    }
+
+    Ok(found.filter(|value| value != "null"))
+}
+
+struct JsonParser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl JsonParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek();
+        self.position += 1;
+        byte
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), &'static str> {
+        self.skip_whitespace();
+        if self.next() == Some(expected) {
+            Ok(())
+        } else {
+            Err("invalid JSON object")
+        }
+    }
+
+    fn string(&mut self) -> Result<String, &'static str> {
+        self.expect(b'"')?;
+        let mut bytes = Vec::new();
+
+        loop {
+            match self.next().ok_or("invalid JSON string")? {
+                b'"' => break,
+                b'\\' => match self.next().ok_or("invalid JSON string")? {
+                    b'"' => bytes.push(b'"'),
+                    b'\\' => bytes.push(b'\\'),
+                    b'/' => bytes.push(b'/'),
+                    b'b' => bytes.push(0x08),
+                    b'f' => bytes.push(0x0c),
+                    b'n' => bytes.push(b'\n'),
+                    b'r' => bytes.push(b'\r'),
+                    b't' => bytes.push(b'\t'),
+                    b'u' => {
+                        let hex = self
+                            .bytes
+                            .get(self.position..self.position + 4)
+                            .and_then(|hex| std::str::from_utf8(hex).ok())
+                            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                            .ok_or("invalid JSON string")?;
+                        self.position += 4;
+                        let character = char::from_u32(hex).unwrap_or(char::REPLACEMENT_CHARACTER);
+                        bytes.extend_from_slice(character.encode_utf8(&mut [0; 4]).as_bytes());
+                    }
+                    _ => return Err("invalid JSON string"),
+                },
+                byte => bytes.push(byte),
+            }
+        }
+
+        String::from_utf8(bytes).map_err(|_| "invalid JSON string")
+    }
+
+    fn skip_value(&mut self) -> Result<(), &'static str> {
+        self.skip_whitespace();
+        let mut depth = 0_usize;
+
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.string()?;
+                    continue;
+                }
+                Some(b'{' | b'[') => depth += 1,
+                Some(b'}' | b']' | b',') if depth == 0 => break,
+                Some(b'}' | b']') => depth -= 1,
+                Some(_) => {}
+                None => return Err("invalid JSON object"),
+            }
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if u32::from(character) < 0x20 => {
+                let _ = std::fmt::Write::write_fmt(
+                    &mut escaped,
+                    format_args!("\\u{:04x}", u32::from(character)),
+                );
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
@@ -0,0 +1,524 @@
+use std::{
+    env, fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use credit_card_types::{
+    luhn_check_digit, mask_card_number, regions, BatchFormat, BatchProcessor, CardBrand,
+    CreditCardPool, CreditCardType,
+};
+
+const USAGE: &str = "\
+Detect, validate and format credit card numbers.
+
+Usage: credit-card-types <COMMAND> [OPTIONS] [NUMBER]...
+
+Commands:
+  detect     Print the card types matching each number
+  validate   Check the card type, length and Luhn checksum of each number
+  format     Insert the card type's gaps into each number
+  mask       Mask each number, keeping the last four digits visible
+  generate   Generate valid test numbers for a brand
+  batch      Enrich the rows of a CSV or JSON Lines file
+
+Options:
+  --json             Print one JSON object per line
+  --show-numbers     Print the input numbers in full instead of masked
+  -h, --help         Print this help
+
+Generate options:
+  --brand <BRAND>    Brand to generate numbers for (required)
+  --count <COUNT>    How many numbers to generate [default: 1]
+  --length <LENGTH>  Length of the numbers [default: the brand's first length]
+  --seed <SEED>      Seed for reproducible numbers
+
+Batch options:
+  --column <COLUMN>  CSV header or JSON key of the card number (required)
+  --format <FORMAT>  `csv` or `jsonl` [default: csv]
+  --delimiter <CHAR> CSV delimiter [default: ,]
+  [FILE]             File to read instead of stdin
+
+If no numbers are given, they are read from stdin, one per line.
+Spaces and dashes inside numbers are ignored. The input numbers are printed
+masked, `mask` only prints the masked numbers.
+
+Exit status:
+  0  every number matched, was valid or was processed
+  1  at least one number did not match or was invalid
+  2  usage error";
+
+const EXIT_NEGATIVE: u8 = 1;
+const EXIT_USAGE: u8 = 2;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Detect,
+    Validate,
+    Format,
+    Mask,
+    Generate,
+    Batch,
+}
+
+struct Options {
+    command: Command,
+    json: bool,
+    show_numbers: bool,
+    numbers: Vec<String>,
+    brand: Option<CardBrand>,
+    count: usize,
+    length: Option<u32>,
+    seed: Option<u64>,
+    column: Option<String>,
+    format: BatchFormat,
+    delimiter: u8,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    // The card types of the region packs enabled at build time are detected too.
+    let mut pool = CreditCardPool::new();
+    pool.extend(regions::ENABLED.iter().cloned());
+    let mut stdout = io::stdout().lock();
+
+    let result = if options.command == Command::Generate {
+        generate(&pool, &options, &mut stdout)
+    } else if options.command == Command::Batch {
+        batch(&pool, &options, &mut stdout)
+    } else if options.numbers.is_empty() {
+        let numbers = io::stdin()
+            .lock()
+            .lines()
+            .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()));
+        run(&pool, &options, numbers, &mut stdout)
+    } else {
+        let numbers = options.numbers.iter().cloned().map(Ok);
+        run(&pool, &options, numbers, &mut stdout)
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(EXIT_NEGATIVE),
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::from(EXIT_USAGE)
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let command = match args.next().as_deref() {
+        None | Some("-h" | "--help" | "help") => return Ok(None),
+        Some("detect") => Command::Detect,
+        Some("validate") => Command::Validate,
+        Some("format") => Command::Format,
+        Some("mask") => Command::Mask,
+        Some("generate") => Command::Generate,
+        Some("batch") => Command::Batch,
+        Some(other) => return Err(format!("unknown command `{other}`")),
+    };
+
+    let mut options = Options {
+        command,
+        json: false,
+        show_numbers: false,
+        numbers: Vec::new(),
+        brand: None,
+        count: 1,
+        length: None,
+        seed: None,
+        column: None,
+        format: BatchFormat::Csv,
+        delimiter: b',',
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--json" => options.json = true,
+            "--show-numbers" => options.show_numbers = true,
+            "--brand" | "--count" | "--length" | "--seed" if command == Command::Generate => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("`{arg}` requires a value"))?;
+                match arg.as_str() {
+                    "--brand" => {
+                        let brand = value
+                            .parse()
+                            .map_err(|_| format!("unknown brand `{value}`"))?;
+                        options.brand = Some(brand);
+                    }
+                    "--count" => options.count = parse_number(&arg, &value)?,
+                    "--length" => options.length = Some(parse_number(&arg, &value)?),
+                    _ => options.seed = Some(parse_number(&arg, &value)?),
+                }
+            }
+            "--column" | "--format" | "--delimiter" if command == Command::Batch => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("`{arg}` requires a value"))?;
+                match (arg.as_str(), value.as_str()) {
+                    ("--column", _) => options.column = Some(value),
+                    ("--format", "csv") => options.format = BatchFormat::Csv,
+                    ("--format", "jsonl") => options.format = BatchFormat::JsonLines,
+                    ("--delimiter", delimiter) if delimiter.len() == 1 => {
+                        options.delimiter = delimiter.as_bytes()[0];
+                    }
+                    _ => return Err(format!("invalid value `{value}` for `{arg}`")),
+                }
+            }
+            "--" => options.numbers.extend(args.by_ref()),
+            option if option.starts_with("--") => {
+                return Err(format!("unknown option `{option}`"));
+            }
+            _ if command == Command::Generate => {
+                return Err(format!("unexpected argument `{arg}`"));
+            }
+            _ => options.numbers.push(arg),
+        }
+    }
+
+    if command == Command::Generate && options.brand.is_none() {
+        return Err("`generate` requires `--brand`".to_string());
+    }
+    if command == Command::Batch {
+        if options.column.is_none() {
+            return Err("`batch` requires `--column`".to_string());
+        }
+        if options.numbers.len() > 1 {
+            return Err("`batch` reads a single file".to_string());
+        }
+    }
+
+    Ok(Some(options))
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for `{option}`"))
+}
+
+/// Runs `detect`, `validate`, `format` or `mask` over all numbers.
+///
+/// Returns `Ok(false)` if at least one number did not match or was invalid.
+fn run(
+    pool: &CreditCardPool,
+    options: &Options,
+    numbers: impl Iterator<Item = io::Result<String>>,
+    out: &mut impl Write,
+) -> Result<bool, String> {
+    let mut all_positive = true;
+
+    for input in numbers {
+        let input = input.map_err(|err| err.to_string())?;
+        let number = normalize(&input);
+
+        let (positive, fields) = match process(pool, options.command, &number) {
+            Ok(result) => result,
+            Err(message) => (false, vec![("error", Value::Str(message))]),
+        };
+        all_positive &= positive;
+
+        // Card numbers are not echoed in full unless asked for, `mask` only prints the masked number.
+        let input = if options.show_numbers {
+            input
+        } else {
+            mask_card_number(input.trim())
+        };
+        let input = (options.show_numbers || options.command != Command::Mask)
+            .then_some(("input", input.as_str()));
+        write_record(out, options.json, input, &fields).map_err(|err| err.to_string())?;
+    }
+
+    Ok(all_positive)
+}
+
+type Fields = Vec<(&'static str, Value)>;
+
+enum Value {
+    Str(String),
+    Bool(bool),
+    List(Vec<String>),
+}
+
+fn process(
+    pool: &CreditCardPool,
+    command: Command,
+    number: &str,
+) -> Result<(bool, Fields), String> {
+    if number.is_empty() || !number.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err("not a card number".to_string());
+    }
+
+    match command {
+        Command::Detect => {
+            let card_types = pool
+                .get_credit_card_type(number)
+                .map_err(|err| err.to_string())?;
+            let brands = card_types
+                .iter()
+                .map(|card_type| card_type.type_.to_string())
+                .collect::<Vec<_>>();
+            Ok((!brands.is_empty(), vec![("brands", Value::List(brands))]))
+        }
+        Command::Validate => {
+            let validation = pool
+                .validate_card_number(number)
+                .map_err(|err| err.to_string())?;
+            let brand = validation
+                .card_type
+                .as_ref()
+                .map_or_else(String::new, |card_type| card_type.type_.to_string());
+            Ok((
+                validation.is_valid(),
+                vec![
+                    ("valid", Value::Bool(validation.is_valid())),
+                    ("brand", Value::Str(brand)),
+                    ("luhn_valid", Value::Bool(validation.luhn_valid)),
+                    ("length_valid", Value::Bool(validation.length_valid)),
+                ],
+            ))
+        }
+        Command::Format => {
+            let card_types = pool
+                .get_credit_card_type(number)
+                .map_err(|err| err.to_string())?;
+            let formatted = match card_types.as_slice() {
+                [card_type] => card_type.format_card_number(number),
+                _ => CreditCardType::default().format_card_number(number),
+            };
+            Ok((
+                card_types.len() == 1,
+                vec![("formatted", Value::Str(formatted))],
+            ))
+        }
+        Command::Mask => Ok((true, vec![("masked", Value::Str(mask_card_number(number)))])),
+        Command::Generate | Command::Batch => unreachable!("command does not read numbers"),
+    }
+}
+
+fn generate(
+    pool: &CreditCardPool,
+    options: &Options,
+    out: &mut impl Write,
+) -> Result<bool, String> {
+    let brand = options.brand.ok_or("`generate` requires `--brand`")?;
+    let card_type = pool
+        .get_all_card_types()
+        .into_iter()
+        .find(|card_type| card_type.type_ == brand)
+        .ok_or_else(|| format!("brand `{brand}` is not in the pool"))?;
+
+    let length = options.length.unwrap_or(card_type.lengths[0]);
+    if !card_type.lengths.contains(&length) {
+        return Err(format!("`{brand}` numbers can not have length {length}"));
+    }
+
+    let mut rng = SplitMix64(options.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| {
+                duration.as_secs() ^ u64::from(duration.subsec_nanos())
+            })
+    }));
+
+    for _ in 0..options.count {
+        let number = generate_number(pool, &card_type, length as usize, &mut rng)
+            .ok_or_else(|| format!("could not generate a `{brand}` number of length {length}"))?;
+        let fields = [("brand", Value::Str(brand.to_string()))];
+        write_record(out, options.json, Some(("number", &number)), &fields)
+            .map_err(|err| err.to_string())?;
+    }
+
+    Ok(true)
+}
+
+/// Runs `batch` over a file or stdin, a summary is printed to stderr.
+///
+/// Returns `Ok(false)` if at least one row was invalid or could not be processed.
+fn batch(pool: &CreditCardPool, options: &Options, out: &mut impl Write) -> Result<bool, String> {
+    let column = options
+        .column
+        .as_deref()
+        .ok_or("`batch` requires `--column`")?;
+    let processor =
+        BatchProcessor::new(pool, options.format, column).with_delimiter(options.delimiter);
+
+    let summary = match options.numbers.first() {
+        Some(path) => {
+            let file = File::open(path).map_err(|err| format!("{path}: {err}"))?;
+            processor.process(BufReader::new(file), out)
+        }
+        None => processor.process(io::stdin().lock(), out),
+    }
+    .map_err(|err| err.to_string())?;
+
+    eprintln!(
+        "{} rows: {} valid, {} invalid, {} masked, {} errors",
+        summary.rows, summary.valid, summary.invalid, summary.masked, summary.errors
+    );
+
+    Ok(summary.invalid == 0 && summary.errors == 0)
+}
+
+/// Generates a Luhn-valid number that is detected as `card_type` by the pool.
+///
+/// Numbers are drawn from a random pattern of the card type, and retried when another card type
+/// with a more specific pattern would claim them.
+fn generate_number(
+    pool: &CreditCardPool,
+    card_type: &CreditCardType,
+    length: usize,
+    rng: &mut SplitMix64,
+) -> Option<String> {
+    for _ in 0..1000 {
+        let pattern = card_type.patterns[rng.index(card_type.patterns.len())];
+        let mut number = match pattern {
+            [prefix] => (*prefix).to_string(),
+            [min, max, ..] => {
+                let min = min.parse::<u64>().ok()?;
+                let max = max.parse::<u64>().ok()?;
+                let prefix = min + rng.below(max - min + 1);
+                format!("{prefix:0width$}", width = pattern[0].len())
+            }
+            [] => return None,
+        };
+        if number.len() >= length {
+            continue;
+        }
+
+        while number.len() < length - 1 {
+            number.push(rng.digit());
+        }
+        number.push(char::from(b'0' + luhn_check_digit(&number)?));
+
+        let detected = pool.validate_card_number(&number).ok()?;
+        if detected.is_valid()
+            && detected
+                .card_type
+                .is_some_and(|detected| detected.type_ == card_type.type_)
+        {
+            return Some(number);
+        }
+    }
+
+    None
+}
+
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn index(&mut self, len: usize) -> usize {
+        usize::try_from(self.below(len as u64)).unwrap_or_default()
+    }
+
+    fn digit(&mut self) -> char {
+        u32::try_from(self.below(10))
+            .ok()
+            .and_then(|digit| char::from_digit(digit, 10))
+            .unwrap_or('0')
+    }
+}
+
+fn normalize(input: &str) -> String {
+    input
+        .trim()
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '-'))
+        .collect()
+}
+
+fn write_record(
+    out: &mut impl Write,
+    json: bool,
+    number: Option<(&str, &str)>,
+    fields: &[(&str, Value)],
+) -> io::Result<()> {
+    if json {
+        let number = number.map(|(key, number)| (key, Value::Str(number.trim().to_string())));
+        for (index, (key, value)) in number.iter().chain(fields).enumerate() {
+            let value = match value {
+                Value::Str(value) => json_string(value),
+                Value::Bool(value) => value.to_string(),
+                Value::List(values) => format!(
+                    "[{}]",
+                    values
+                        .iter()
+                        .map(|value| json_string(value))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ),
+            };
+            let separator = if index == 0 { "{" } else { "," };
+            write!(out, "{separator}{}:{value}", json_string(key))?;
+        }
+        return writeln!(out, "}}");
+    }
+
+    let mut separator = "";
+    if let Some((_, number)) = number {
+        write!(out, "{}", number.trim())?;
+        separator = "\t";
+    }
+    for (key, value) in fields {
+        match value {
+            Value::Str(value) if value.is_empty() => continue,
+            Value::Str(value) => write!(out, "{separator}{value}")?,
+            Value::Bool(value) => write!(out, "{separator}{key}={value}")?,
+            Value::List(values) if values.is_empty() => write!(out, "{separator}unknown")?,
+            Value::List(values) => write!(out, "{separator}{}", values.join(","))?,
+        }
+        separator = "\t";
+    }
+    writeln!(out)
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if character.is_control() => {
+                let _ = fmt::Write::write_fmt(
+                    &mut escaped,
+                    format_args!("\\u{:04x}", u32::from(character)),
+                );
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
@@ -0,0 +1,302 @@
+use crate::{
+    card_brand::CardBrand,
+    card_types::{Code, CreditCardPool, CreditCardType, DEFAULT_CARD_TYPES},
+    effective::PatternValidity,
+    errors::CardTypeError,
+    regions,
+};
+
+/// The longest card number allowed by ISO/IEC 7812.
+const MAX_LENGTH: u32 = 19;
+
+/// A named set of brands to start a [`CreditCardPoolBuilder`] from.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Preset {
+    /// Every card type of [`DEFAULT_CARD_TYPES`].
+    Default,
+    /// Visa, Mastercard, American Express and Discover.
+    Major,
+    /// The networks accepted worldwide: the major ones, Diners Club, JCB and `UnionPay`.
+    International,
+    /// The international networks and the Brazilian ones: Elo, Hiper and Hipercard.
+    Brazil,
+}
+
+impl Preset {
+    /// Returns the brands of the preset.
+    #[must_use]
+    pub const fn brands(self) -> &'static [CardBrand] {
+        match self {
+            Preset::Default => &[
+                CardBrand::AmericanExpress,
+                CardBrand::DinersClub,
+                CardBrand::Discover,
+                CardBrand::Elo,
+                CardBrand::Hiper,
+                CardBrand::Hipercard,
+                CardBrand::Jcb,
+                CardBrand::Maestro,
+                CardBrand::Mastercard,
+                CardBrand::Mir,
+                CardBrand::UnionPay,
+                CardBrand::Visa,
+            ],
+            Preset::Major => &[
+                CardBrand::AmericanExpress,
+                CardBrand::Discover,
+                CardBrand::Mastercard,
+                CardBrand::Visa,
+            ],
+            Preset::International => &[
+                CardBrand::AmericanExpress,
+                CardBrand::DinersClub,
+                CardBrand::Discover,
+                CardBrand::Jcb,
+                CardBrand::Mastercard,
+                CardBrand::UnionPay,
+                CardBrand::Visa,
+            ],
+            Preset::Brazil => &[
+                CardBrand::AmericanExpress,
+                CardBrand::DinersClub,
+                CardBrand::Discover,
+                CardBrand::Elo,
+                CardBrand::Hiper,
+                CardBrand::Hipercard,
+                CardBrand::Jcb,
+                CardBrand::Mastercard,
+                CardBrand::UnionPay,
+                CardBrand::Visa,
+            ],
+        }
+    }
+}
+
+/// Builds a [`CreditCardPool`] with the brands a merchant accepts.
+///
+/// The builder starts from no brands, all the known ones or a [`Preset`]. Brands are then enabled and disabled by
+/// [`CardBrand`], and fields of the enabled card types can be overridden. [`CreditCardPoolBuilder::build`] checks the
+/// result, the first mistake is returned as an error.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{CardBrand, CreditCardPoolBuilder, Preset};
+///
+/// let pool = CreditCardPoolBuilder::preset(Preset::Major)
+///     .disable(CardBrand::Discover)
+///     .enable(CardBrand::Jcb)
+///     .lengths(CardBrand::Visa, &[16])
+///     .build()
+///     .unwrap();
+///
+/// assert!(pool.validate_card_number("4111111111111111").unwrap().is_valid());
+/// assert!(!pool.validate_card_number("4111111111111111003").unwrap().is_valid());
+/// assert!(pool.get_credit_card_type("6011111111111117").unwrap().is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CreditCardPoolBuilder {
+    pool: CreditCardPool,
+    error: Option<CardTypeError>,
+}
+
+impl CreditCardPoolBuilder {
+    /// Starts from a pool without card types.
+    #[must_use]
+    pub const fn none() -> CreditCardPoolBuilder {
+        CreditCardPoolBuilder {
+            pool: CreditCardPool::new_empty(),
+            error: None,
+        }
+    }
+
+    /// Starts from every known card type: the default ones and the ones of the enabled [`regions`] packs.
+    #[must_use]
+    pub fn all() -> CreditCardPoolBuilder {
+        let mut pool = CreditCardPool::new();
+        pool.extend(regions::ENABLED.iter().cloned());
+
+        CreditCardPoolBuilder { pool, error: None }
+    }
+
+    /// Starts from the card types of a preset.
+    #[must_use]
+    pub fn preset(preset: Preset) -> CreditCardPoolBuilder {
+        preset
+            .brands()
+            .iter()
+            .fold(CreditCardPoolBuilder::none(), |builder, brand| {
+                builder.enable(*brand)
+            })
+    }
+
+    /// Enables the known card type of a brand, replacing a card type already in the pool.
+    ///
+    /// Building fails with [`CardTypeError::UnknownCardBrand`] if the brand has no default or enabled regional card
+    /// type, use [`CreditCardPoolBuilder::card_type`] for custom card types.
+    #[must_use]
+    pub fn enable(mut self, brand: CardBrand) -> CreditCardPoolBuilder {
+        let card_type = DEFAULT_CARD_TYPES
+            .iter()
+            .chain(regions::ENABLED)
+            .find(|card_type| card_type.type_ == brand);
+
+        match card_type {
+            Some(card_type) => self.pool.insert_unchecked(card_type.clone()),
+            None => self.fail(CardTypeError::UnknownCardBrand),
+        }
+        self
+    }
+
+    /// Disables a brand, brands that are not in the pool are ignored.
+    #[must_use]
+    pub fn disable(mut self, brand: CardBrand) -> CreditCardPoolBuilder {
+        self.pool.remove_card_type(brand);
+        self
+    }
+
+    /// Adds a card type, replacing the card type of the same brand.
+    #[must_use]
+    pub fn card_type(mut self, card_type: CreditCardType) -> CreditCardPoolBuilder {
+        self.pool.insert_unchecked(card_type);
+        self
+    }
+
+    /// Changes the card type of an enabled brand.
+    ///
+    /// Building fails with [`CardTypeError::UnknownCardBrand`] if the brand is not in the pool.
+    #[must_use]
+    pub fn modify(
+        mut self,
+        brand: CardBrand,
+        modify: impl FnOnce(&mut CreditCardType),
+    ) -> CreditCardPoolBuilder {
+        let card_type = self
+            .pool
+            .card_types()
+            .iter()
+            .find(|card_type| card_type.type_ == brand);
+
+        match card_type {
+            Some(card_type) => {
+                let mut card_type = card_type.clone();
+                modify(&mut card_type);
+                // A changed brand moves the card type, so the pool stays sorted.
+                self.pool.remove_card_type(brand);
+                self.pool.insert_unchecked(card_type);
+            }
+            None => self.fail(CardTypeError::UnknownCardBrand),
+        }
+        self
+    }
+
+    /// Overrides the allowed lengths of an enabled brand.
+    #[must_use]
+    pub fn lengths(self, brand: CardBrand, lengths: &'static [u32]) -> CreditCardPoolBuilder {
+        self.modify(brand, |card_type| card_type.lengths = lengths)
+    }
+
+    /// Overrides the gaps of an enabled brand.
+    #[must_use]
+    pub fn gaps(self, brand: CardBrand, gaps: &'static [u32]) -> CreditCardPoolBuilder {
+        self.modify(brand, |card_type| card_type.gaps = gaps)
+    }
+
+    /// Overrides the patterns of an enabled brand.
+    ///
+    /// The [validity](CreditCardType::validity) of the previous patterns is dropped, set it again with
+    /// [`CreditCardPoolBuilder::validity`].
+    #[must_use]
+    pub fn patterns(
+        self,
+        brand: CardBrand,
+        patterns: &'static [&'static [&'static str]],
+    ) -> CreditCardPoolBuilder {
+        self.modify(brand, |card_type| {
+            card_type.patterns = patterns;
+            card_type.validity = &[];
+        })
+    }
+
+    /// Overrides the dates the patterns of an enabled brand are in force.
+    #[must_use]
+    pub fn validity(
+        self,
+        brand: CardBrand,
+        validity: &'static [PatternValidity],
+    ) -> CreditCardPoolBuilder {
+        self.modify(brand, |card_type| card_type.validity = validity)
+    }
+
+    /// Overrides the security code of an enabled brand.
+    #[must_use]
+    pub fn code(self, brand: CardBrand, code: Code) -> CreditCardPoolBuilder {
+        self.modify(brand, |card_type| card_type.code = code)
+    }
+
+    /// Checks the card types and returns the pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of the builder calls, or [`CardTypeError::InvalidCardType`] if a card type has no
+    /// patterns or lengths, a malformed pattern, a length over 19, gaps that are not increasing and inside the number,
+    /// or a [validity](CreditCardType::validity) of an unknown pattern or ending before it starts.
+    pub fn build(self) -> Result<CreditCardPool, CardTypeError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        for card_type in self.pool.card_types() {
+            check_card_type(card_type)
+                .map_err(|reason| CardTypeError::InvalidCardType(card_type.type_, reason))?;
+        }
+
+        Ok(self.pool)
+    }
+
+    fn fail(&mut self, error: CardTypeError) {
+        self.error.get_or_insert(error);
+    }
+}
+
+fn check_card_type(card_type: &CreditCardType) -> Result<(), &'static str> {
+    if card_type.patterns.is_empty() {
+        return Err("no patterns");
+    }
+
+    for pattern in card_type
+        .patterns
+        .iter()
+        .chain(card_type.ranges.iter().map(|range| &range.pattern))
+    {
+        let is_prefix =
+            |prefix: &str| !prefix.is_empty() && prefix.bytes().all(|byte| byte.is_ascii_digit());
+
+        match pattern {
+            [prefix] if is_prefix(prefix) => {}
+            [min, max]
+                if is_prefix(min) && min.len() == max.len() && is_prefix(max) && min <= max => {}
+            _ => return Err("malformed pattern"),
+        }
+    }
+
+    card_type.check_validity()?;
+
+    let Some(max_length) = card_type.lengths.iter().max() else {
+        return Err("no lengths");
+    };
+    if *max_length > MAX_LENGTH || card_type.lengths.contains(&0) {
+        return Err("length out of range");
+    }
+
+    let mut previous_gap = 0;
+    for gap in card_type.gaps {
+        if *gap <= previous_gap || gap >= max_length {
+            return Err("gaps not increasing or outside of the number");
+        }
+        previous_gap = *gap;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,177 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use crate::errors::CardTypeError;
+
+/// A card network (brand).
+///
+/// Every built-in card type has its own variant, card types added by the user can use [`CardBrand::Custom`]. Some
+/// variants are only used as co-badged networks, see [`CardRange`](crate::CardRange), or by the card types of the
+/// [`regions`](crate::regions) packs.
+///
+/// Brands are compared, ordered and hashed by their string representation, so `CardBrand::Custom("visa")` is equal to `CardBrand::Visa`.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::CardBrand;
+///
+/// let brand: CardBrand = "american-express".parse().unwrap();
+///
+/// assert_eq!(brand, CardBrand::AmericanExpress);
+/// assert_eq!(brand.to_string(), "american-express");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    AmericanExpress,
+    DinersClub,
+    Discover,
+    Jcb,
+    UnionPay,
+    Maestro,
+    Elo,
+    Mir,
+    Hiper,
+    Hipercard,
+    CartesBancaires,
+    Dankort,
+    Eftpos,
+    Bancontact,
+    Napas,
+    RuPay,
+    Troy,
+    Verve,
+    Custom(&'static str),
+}
+
+impl CardBrand {
+    /// All the built-in brands, custom brands are not included.
+    pub const BUILT_IN: &'static [CardBrand] = &[
+        CardBrand::Visa,
+        CardBrand::Mastercard,
+        CardBrand::AmericanExpress,
+        CardBrand::DinersClub,
+        CardBrand::Discover,
+        CardBrand::Jcb,
+        CardBrand::UnionPay,
+        CardBrand::Maestro,
+        CardBrand::Elo,
+        CardBrand::Mir,
+        CardBrand::Hiper,
+        CardBrand::Hipercard,
+        CardBrand::CartesBancaires,
+        CardBrand::Dankort,
+        CardBrand::Eftpos,
+        CardBrand::Bancontact,
+        CardBrand::Napas,
+        CardBrand::RuPay,
+        CardBrand::Troy,
+        CardBrand::Verve,
+    ];
+
+    /// Returns the identifier of the brand, e.g. `"american-express"`.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            CardBrand::Visa => "visa",
+            CardBrand::Mastercard => "mastercard",
+            CardBrand::AmericanExpress => "american-express",
+            CardBrand::DinersClub => "diners-club",
+            CardBrand::Discover => "discover",
+            CardBrand::Jcb => "jcb",
+            CardBrand::UnionPay => "unionpay",
+            CardBrand::Maestro => "maestro",
+            CardBrand::Elo => "elo",
+            CardBrand::Mir => "mir",
+            CardBrand::Hiper => "hiper",
+            CardBrand::Hipercard => "hipercard",
+            CardBrand::CartesBancaires => "cartes-bancaires",
+            CardBrand::Dankort => "dankort",
+            CardBrand::Eftpos => "eftpos",
+            CardBrand::Bancontact => "bancontact",
+            CardBrand::Napas => "napas",
+            CardBrand::RuPay => "rupay",
+            CardBrand::Troy => "troy",
+            CardBrand::Verve => "verve",
+            CardBrand::Custom(name) => name,
+        }
+    }
+}
+
+impl FromStr for CardBrand {
+    type Err = CardTypeError;
+
+    /// Parses a built-in brand from its identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::UnknownCardBrand`] if the identifier is not a built-in brand.
+    /// Custom brands can not be parsed, use [`CardBrand::Custom`] directly.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CardBrand::BUILT_IN
+            .iter()
+            .find(|brand| brand.as_str() == s)
+            .copied()
+            .ok_or(CardTypeError::UnknownCardBrand)
+    }
+}
+
+impl fmt::Display for CardBrand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq for CardBrand {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for CardBrand {}
+
+impl PartialOrd for CardBrand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CardBrand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for CardBrand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for CardBrand {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for CardBrand {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<String> for CardBrand {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
@@ -0,0 +1,448 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{fmt, iter};
+
+#[cfg(feature = "alloc")]
+use crate::CreditCardPool;
+use crate::{
+    card_brand::CardBrand,
+    card_types::CreditCardType,
+    errors::CardTypeError,
+    static_pool::{StaticCardPool, StaticMatches},
+    utils::matches,
+};
+
+/// How a card is funded.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FundingType {
+    Credit,
+    Debit,
+    Prepaid,
+    /// A card whose balance has to be paid in full every month.
+    Charge,
+    /// No funding information is known for the card.
+    #[default]
+    Unknown,
+}
+
+impl FundingType {
+    /// Returns the identifier of the funding type, e.g. `"debit"`.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            FundingType::Credit => "credit",
+            FundingType::Debit => "debit",
+            FundingType::Prepaid => "prepaid",
+            FundingType::Charge => "charge",
+            FundingType::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for FundingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What the numbers of a range are issued for.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum RangeKind {
+    /// Cards issued to cardholders, the default.
+    #[default]
+    Regular,
+    /// Network tokens, like the device PANs of Apple Pay and Google Pay, which stand for a card number.
+    NetworkToken,
+    /// Virtual cards issued for online or single use payments.
+    Virtual,
+}
+
+impl RangeKind {
+    /// Returns the identifier of the kind, e.g. `"network-token"`.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            RangeKind::Regular => "regular",
+            RangeKind::NetworkToken => "network-token",
+            RangeKind::Virtual => "virtual",
+        }
+    }
+}
+
+impl fmt::Display for RangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A range of card numbers within a card type, with information about the cards issued in it.
+///
+/// Ranges don't change which card types match a number, that is decided by the
+/// [`patterns`](CreditCardType::patterns). They describe a part of the card type, like the networks its cards are
+/// co-badged with. When several ranges of a card type match a number, each piece of information comes from the range
+/// with the longest pattern that declares it, so a token range nested in a debit range holds debit tokens.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use credit_card_types::{CardBrand, CardRange, CreditCardPool, CreditCardType, DEFAULT_CARD_TYPES};
+///
+/// // A BIN range of Mastercard debit cards co-badged with eftpos, taken from an issuer's BIN table.
+/// const RANGES: &[CardRange] =
+///     &[CardRange::new(&["516300", "516399"]).with_co_badged(&[CardBrand::Eftpos])];
+///
+/// let mastercard = DEFAULT_CARD_TYPES
+///     .iter()
+///     .find(|card_type| card_type.type_ == CardBrand::Mastercard)
+///     .unwrap();
+///
+/// let mut pool = CreditCardPool::new();
+/// pool.insert_card_type(CreditCardType {
+///     ranges: RANGES,
+///     ..mastercard.clone()
+/// });
+///
+/// let card_match = &pool.get_card_matches("5163610000000000").unwrap()[0];
+///
+/// assert_eq!(
+///     card_match.networks().collect::<Vec<_>>(),
+///     [CardBrand::Mastercard, CardBrand::Eftpos]
+/// );
+/// # }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CardRange {
+    /// A prefix like `&["4571"]`, or an inclusive range of prefixes like `&["5000", "5099"]`.
+    pub pattern: &'static [&'static str],
+    /// The networks the cards are co-badged with, besides the brand of the card type.
+    pub co_badged: &'static [CardBrand],
+    /// The funding type of the cards.
+    pub funding: FundingType,
+    /// What the numbers are issued for.
+    pub kind: RangeKind,
+}
+
+impl CardRange {
+    /// Creates a range without extra information, see the `with_*` methods.
+    #[must_use]
+    pub const fn new(pattern: &'static [&'static str]) -> CardRange {
+        CardRange {
+            pattern,
+            co_badged: &[],
+            funding: FundingType::Unknown,
+            kind: RangeKind::Regular,
+        }
+    }
+
+    /// Sets the networks the cards are co-badged with.
+    #[must_use]
+    pub const fn with_co_badged(self, co_badged: &'static [CardBrand]) -> CardRange {
+        CardRange { co_badged, ..self }
+    }
+
+    /// Sets the funding type of the cards.
+    #[must_use]
+    pub const fn with_funding(self, funding: FundingType) -> CardRange {
+        CardRange { funding, ..self }
+    }
+
+    /// Sets what the numbers are issued for.
+    #[must_use]
+    pub const fn with_kind(self, kind: RangeKind) -> CardRange {
+        CardRange { kind, ..self }
+    }
+
+    /// Returns `true` if `card_number` is long enough to cover the pattern, and matches it.
+    fn contains(&self, card_number: &str) -> Result<bool, CardTypeError> {
+        if card_number.len() < self.pattern[0].len() {
+            return Ok(false);
+        }
+
+        matches(card_number, self.pattern)
+    }
+}
+
+impl CreditCardType {
+    /// Returns the most specific [range](CreditCardType::ranges) containing `card_number`.
+    ///
+    /// A range only contains a number that is at least as long as its pattern, so partial numbers don't get the
+    /// information of a range they may not end up in. The range may not declare everything known about the number, see
+    /// [`co_badged`](CreditCardType::co_badged), [`funding`](CreditCardType::funding) and
+    /// [`kind`](CreditCardType::kind).
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn range(&self, card_number: &str) -> Result<Option<&'static CardRange>, CardTypeError> {
+        let mut best_range: Option<&'static CardRange> = None;
+
+        for range in self.ranges {
+            if range.contains(card_number)?
+                && best_range
+                    .is_none_or(|best_range| range.pattern[0].len() > best_range.pattern[0].len())
+            {
+                best_range = Some(range);
+            }
+        }
+
+        Ok(best_range)
+    }
+
+    /// Returns an attribute of the most specific range containing `card_number` that declares it, i.e. for which
+    /// `attribute` returns `Some`.
+    fn range_attribute<T>(
+        &self,
+        card_number: &str,
+        attribute: impl Fn(&'static CardRange) -> Option<T>,
+    ) -> Result<Option<T>, CardTypeError> {
+        let mut best: Option<(usize, T)> = None;
+
+        for range in self.ranges {
+            if !range.contains(card_number)? {
+                continue;
+            }
+            let length = range.pattern[0].len();
+            if best
+                .as_ref()
+                .is_some_and(|(best_length, _)| length <= *best_length)
+            {
+                continue;
+            }
+            if let Some(value) = attribute(range) {
+                best = Some((length, value));
+            }
+        }
+
+        Ok(best.map(|(_, value)| value))
+    }
+
+    /// Returns the networks `card_number` is co-badged with, besides the brand of the card type, from the most
+    /// specific range containing it that declares co-badged networks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::{CardBrand, StaticCardPool};
+    ///
+    /// let pool = StaticCardPool::new();
+    /// let card_type = pool.get_credit_card_type("4571000000000001").unwrap().next().unwrap();
+    ///
+    /// assert_eq!(card_type.type_, CardBrand::Visa);
+    /// assert_eq!(card_type.co_badged("4571000000000001").unwrap(), [CardBrand::Dankort]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn co_badged(&self, card_number: &str) -> Result<&'static [CardBrand], CardTypeError> {
+        Ok(self
+            .range_attribute(card_number, |range| {
+                Some(range.co_badged).filter(|co_badged| !co_badged.is_empty())
+            })?
+            .unwrap_or_default())
+    }
+
+    /// Returns the funding type of `card_number`, [`FundingType::Unknown`] if no range containing it declares one.
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn funding(&self, card_number: &str) -> Result<FundingType, CardTypeError> {
+        Ok(self
+            .range_attribute(card_number, |range| {
+                Some(range.funding).filter(|funding| *funding != FundingType::Unknown)
+            })?
+            .unwrap_or_default())
+    }
+
+    /// Returns what `card_number` is issued for, [`RangeKind::Regular`] if no range containing it declares it.
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn kind(&self, card_number: &str) -> Result<RangeKind, CardTypeError> {
+        Ok(self
+            .range_attribute(card_number, |range| {
+                Some(range.kind).filter(|kind| *kind != RangeKind::Regular)
+            })?
+            .unwrap_or_default())
+    }
+}
+
+/// A card type matching a card number, with the information of the [ranges](CardRange) containing it.
+///
+/// Returned by [`CreditCardPool::get_card_matches`](crate::CreditCardPool::get_card_matches) and
+/// [`StaticCardPool::get_card_matches`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CardMatch {
+    /// The matching card type, its brand is the primary network.
+    pub card_type: CreditCardType,
+    /// The networks the card is co-badged with, empty for single network cards.
+    pub co_badged: &'static [CardBrand],
+    /// The funding type of the card.
+    pub funding: FundingType,
+    /// What the number is issued for.
+    pub kind: RangeKind,
+}
+
+impl CardMatch {
+    /// Returns the primary network.
+    #[must_use]
+    pub fn primary(&self) -> CardBrand {
+        self.card_type.type_
+    }
+
+    /// Returns `true` if the card carries more than one network.
+    #[must_use]
+    pub fn is_co_badged(&self) -> bool {
+        !self.co_badged.is_empty()
+    }
+
+    /// Returns `true` if the number is a network token rather than a card number.
+    #[must_use]
+    pub fn is_network_token(&self) -> bool {
+        self.kind == RangeKind::NetworkToken
+    }
+
+    /// Returns `true` if the number is of a virtual card.
+    #[must_use]
+    pub fn is_virtual(&self) -> bool {
+        self.kind == RangeKind::Virtual
+    }
+
+    /// Returns the primary network followed by the co-badged networks.
+    pub fn networks(&self) -> impl Iterator<Item = CardBrand> + '_ {
+        iter::once(self.primary()).chain(self.co_badged.iter().copied())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CreditCardPool {
+    /// Returns the card types matching the given card number, with the networks each card is co-badged with.
+    ///
+    /// The card types are the same as the ones of [`CreditCardPool::get_credit_card_type`]. Co-badged cards, like
+    /// Visa/Dankort or Elo/Discover, let the cardholder choose the network.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::{CardBrand, CreditCardPool};
+    ///
+    /// let pool = CreditCardPool::new();
+    ///
+    /// let card_matches = pool.get_card_matches("6500310000000000").unwrap();
+    ///
+    /// assert_eq!(card_matches.len(), 1);
+    /// assert_eq!(card_matches[0].primary(), CardBrand::Elo);
+    /// assert_eq!(card_matches[0].co_badged, [CardBrand::Discover]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn get_card_matches(
+        &self,
+        card_number: impl AsRef<str>,
+    ) -> Result<Vec<CardMatch>, CardTypeError> {
+        Ok(self
+            .as_static_pool()
+            .get_card_matches(card_number.as_ref())?
+            .collect())
+    }
+
+    /// Returns the best-known funding type of the given card number.
+    ///
+    /// See [`StaticCardPool::funding_type`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::{CreditCardPool, FundingType};
+    ///
+    /// let pool = CreditCardPool::new();
+    ///
+    /// assert_eq!(pool.funding_type("378282246310005").unwrap(), FundingType::Charge);
+    /// assert_eq!(pool.funding_type("4026000000000002").unwrap(), FundingType::Debit);
+    /// assert_eq!(pool.funding_type("4111111111111111").unwrap(), FundingType::Unknown);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn funding_type(&self, card_number: impl AsRef<str>) -> Result<FundingType, CardTypeError> {
+        self.as_static_pool().funding_type(card_number.as_ref())
+    }
+}
+
+impl<'a> StaticCardPool<'a> {
+    /// Returns an iterator over the card types matching the given card number, with the networks each card is
+    /// co-badged with.
+    ///
+    /// See [`CreditCardPool::get_card_matches`](crate::CreditCardPool::get_card_matches).
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn get_card_matches<'b>(
+        &self,
+        card_number: &'b str,
+    ) -> Result<StaticCardMatches<'a, 'b>, CardTypeError> {
+        let matches = self.get_credit_card_type(card_number)?;
+        for card_type in self.card_types() {
+            card_type.range(card_number)?;
+        }
+
+        Ok(StaticCardMatches {
+            card_number,
+            matches,
+        })
+    }
+
+    /// Returns the best-known funding type of the given card number.
+    ///
+    /// This is the funding type of the matching card types when they all agree, like [`FundingType::Debit`] for
+    /// Maestro or [`FundingType::Charge`] for American Express. It falls back to [`FundingType::Unknown`] when no card
+    /// type matches, the matches disagree, or no range declares a funding type.
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn funding_type(&self, card_number: &str) -> Result<FundingType, CardTypeError> {
+        let mut card_matches = self.get_card_matches(card_number)?;
+        let Some(first) = card_matches.next() else {
+            return Ok(FundingType::Unknown);
+        };
+
+        if card_matches.all(|card_match| card_match.funding == first.funding) {
+            Ok(first.funding)
+        } else {
+            Ok(FundingType::Unknown)
+        }
+    }
+}
+
+/// Iterator over the card types matching a card number, returned by [`StaticCardPool::get_card_matches`].
+#[derive(Debug, Clone)]
+pub struct StaticCardMatches<'a, 'b> {
+    card_number: &'b str,
+    matches: StaticMatches<'a, 'b>,
+}
+
+impl Iterator for StaticCardMatches<'_, '_> {
+    type Item = CardMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let card_type = self.matches.next()?;
+        // Errors were already returned by `StaticCardPool::get_card_matches`.
+        Some(CardMatch {
+            co_badged: card_type.co_badged(self.card_number).unwrap_or_default(),
+            funding: card_type.funding(self.card_number).unwrap_or_default(),
+            kind: card_type.kind(self.card_number).unwrap_or_default(),
+            card_type,
+        })
+    }
+}
@@ -1,34 +1,57 @@
-#![allow(clippy::too_many_lines)]
-
-use std::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
 
 use crate::{
-    errors::CardTypeError,
-    utils::{add_best_match_to_results, find_best_match},
+    card_brand::CardBrand,
+    card_range::{CardRange, FundingType},
+    effective::{Date, PatternValidity},
 };
+#[cfg(feature = "alloc")]
+use crate::{errors::CardTypeError, StaticCardPool};
 
 /// A struct representing all credit card types.
 ///
-/// The `CreditCardPool` struct is a wrapper around a `BTreeMap` of `CreditCardType`s.
+/// The `CreditCardPool` struct is a wrapper around a slice of `CreditCardType`s sorted by brand. The default card types
+/// are borrowed from [`DEFAULT_CARD_TYPES`], they are only copied when the pool is modified. So creating a pool is free,
+/// and it can be used in `const` and `static` items.
+///
+/// Requires the `alloc` feature, see [`StaticCardPool`] for targets without an allocator.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::CreditCardPool;
+///
+/// static POOL: CreditCardPool = CreditCardPool::new();
+///
+/// println!("{:?}", POOL.get_credit_card_type("4111111111111111"));
+/// ```
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct CreditCardPool(BTreeMap<&'static str, CreditCardType>);
+pub struct CreditCardPool(Cow<'static, [CreditCardType]>);
 
+#[cfg(feature = "alloc")]
 impl CreditCardPool {
     /// Create a new `CreditCardPool` with the default credit card types.
     #[must_use]
-    pub fn new() -> CreditCardPool {
-        CreditCardPool::default()
+    pub const fn new() -> CreditCardPool {
+        CreditCardPool(Cow::Borrowed(DEFAULT_CARD_TYPES))
     }
 
     /// Create a new empty `CreditCardPool`.
     #[must_use]
-    pub fn new_empty() -> CreditCardPool {
-        CreditCardPool(BTreeMap::new())
+    pub const fn new_empty() -> CreditCardPool {
+        CreditCardPool(Cow::Borrowed(&[]))
     }
 
     /// Inserts a new card type into the pool.
     ///
-    /// If a card type with the same type already exists, it will be overwritten. This can be used to modify the existing card types.
+    /// If a card type with the same brand already exists, it will be overwritten. This can be used to modify the existing card types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [validity](CreditCardType::validity) of the card type is of a pattern the card type doesn't have, or
+    /// ends before it starts. [`CreditCardPoolBuilder`](crate::CreditCardPoolBuilder) returns an error instead.
     ///
     /// # Example
     ///
@@ -42,7 +65,22 @@ impl CreditCardPool {
     /// println!("{:?}", pool.get_credit_card_type("123456789"));
     /// ```
     pub fn insert_card_type(&mut self, card_type: CreditCardType) {
-        self.0.insert(card_type.type_, card_type);
+        if let Err(reason) = card_type.check_validity() {
+            panic!(
+                "{}",
+                CardTypeError::InvalidCardType(card_type.type_, reason)
+            );
+        }
+
+        self.insert_unchecked(card_type);
+    }
+
+    /// Inserts a card type without checking it, for the builder which checks every card type when building.
+    pub(crate) fn insert_unchecked(&mut self, card_type: CreditCardType) {
+        match self.find(card_type.type_) {
+            Ok(index) => self.0.to_mut()[index] = card_type,
+            Err(index) => self.0.to_mut().insert(index, card_type),
+        }
     }
 
     /// Removes a card type from the pool.
@@ -50,16 +88,18 @@ impl CreditCardPool {
     /// # Example
     ///
     /// ```
-    /// use credit_card_types::{CreditCardPool, CreditCardType};
+    /// use credit_card_types::{CardBrand, CreditCardPool};
     ///
     /// let mut pool = CreditCardPool::new();
     ///
-    /// pool.remove_card_type("visa");
+    /// pool.remove_card_type(CardBrand::Visa);
     ///
     /// println!("{:?}", pool.get_all_card_types());
     /// ```
-    pub fn remove_card_type(&mut self, type_: &str) {
-        self.0.remove(type_);
+    pub fn remove_card_type(&mut self, brand: CardBrand) {
+        if let Ok(index) = self.find(brand) {
+            self.0.to_mut().remove(index);
+        }
     }
 
     /// Returns all the cards that match the given card number.
@@ -69,6 +109,9 @@ impl CreditCardPool {
     /// If it returns a vector with more than one element, it means that more than one card type matches the given card number.
     /// This can happen if the card number is too short to be identified as a specific card type.
     ///
+    /// When the number is long enough, the card type with the longest matching pattern is returned alone. If two card
+    /// types match with patterns of the same length, the one whose range covers fewer prefixes wins.
+    ///
     /// # Example
     ///
     /// ```
@@ -88,26 +131,46 @@ impl CreditCardPool {
         &self,
         card_number: impl AsRef<str>,
     ) -> Result<Vec<CreditCardType>, CardTypeError> {
-        let card_number = card_number.as_ref();
-
-        let mut all_cards = self.get_all_card_types();
-        if card_number.is_empty() {
-            return Ok(all_cards);
-        }
-
-        let mut results = Vec::new();
-
-        for card_type in &mut all_cards {
-            add_best_match_to_results(card_number, card_type, &mut results)?;
-        }
-
-        let best_match = find_best_match(&results);
-
-        if let Some(best_match) = best_match {
-            return Ok(vec![best_match.clone()]);
-        }
+        Ok(self
+            .as_static_pool()
+            .get_credit_card_type(card_number.as_ref())?
+            .collect())
+    }
 
-        Ok(results.into_iter().cloned().collect())
+    /// Returns all the cards that match the given card number with the patterns in force on `date`, to classify past
+    /// transactions with the rules of the time.
+    ///
+    /// Patterns are matched as in [`CreditCardPool::get_credit_card_type`], leaving out the ones whose
+    /// [validity](CreditCardType::validity) doesn't contain the date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::{CardBrand, CreditCardPool, Date};
+    ///
+    /// let pool = CreditCardPool::new();
+    ///
+    /// // The Mastercard 2-series is in force since 2017.
+    /// let before = Date::new(2016, 12, 31).unwrap();
+    /// assert!(pool.get_credit_card_type_on("2221000000000009", before).unwrap().is_empty());
+    ///
+    /// let after = Date::new(2017, 1, 1).unwrap();
+    /// let result = pool.get_credit_card_type_on("2221000000000009", after).unwrap();
+    /// assert_eq!(result[0].type_, CardBrand::Mastercard);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn get_credit_card_type_on(
+        &self,
+        card_number: impl AsRef<str>,
+        date: Date,
+    ) -> Result<Vec<CreditCardType>, CardTypeError> {
+        Ok(self
+            .as_static_pool()
+            .get_credit_card_type_on(card_number.as_ref(), date)?
+            .collect())
     }
 
     /// Returns all card types in the card pool.
@@ -123,281 +186,353 @@ impl CreditCardPool {
     /// ```
     #[must_use]
     pub fn get_all_card_types(&self) -> Vec<CreditCardType> {
-        self.0.values().cloned().collect()
+        self.0.to_vec()
+    }
+
+    /// Returns all card types in the card pool, sorted by brand, without copying them.
+    #[must_use]
+    pub fn card_types(&self) -> &[CreditCardType] {
+        &self.0
+    }
+
+    /// Returns a [`StaticCardPool`] borrowing the card types of this pool.
+    #[must_use]
+    pub fn as_static_pool(&self) -> StaticCardPool<'_> {
+        StaticCardPool::from_card_types(&self.0)
+    }
+
+    pub(crate) fn find(&self, brand: CardBrand) -> Result<usize, usize> {
+        self.0
+            .binary_search_by(|card_type| card_type.type_.cmp(&brand))
+    }
+}
+
+/// Inserts every card type with [`CreditCardPool::insert_card_type`].
+///
+/// # Panics
+///
+/// Panics on a card type with an invalid [validity](CreditCardType::validity), like
+/// [`CreditCardPool::insert_card_type`].
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{regions, CreditCardPool};
+///
+/// let mut pool = CreditCardPool::new();
+///
+/// pool.extend(regions::ENABLED.iter().cloned());
+/// ```
+#[cfg(feature = "alloc")]
+impl Extend<CreditCardType> for CreditCardPool {
+    fn extend<T: IntoIterator<Item = CreditCardType>>(&mut self, iter: T) {
+        for card_type in iter {
+            self.insert_card_type(card_type);
+        }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Default for CreditCardPool {
     fn default() -> Self {
-        let cards = [
-            (
-                "visa",
-                CreditCardType {
-                    nice_type: "Visa",
-                    type_: "visa",
-                    patterns: &[&["4"]],
-                    gaps: &[4, 8, 12],
-                    lengths: &[16, 18, 19],
-                    code: Code {
-                        name: "CVV",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "mastercard",
-                CreditCardType {
-                    nice_type: "Mastercard",
-                    type_: "mastercard",
-                    patterns: &[
-                        &["51", "55"],
-                        &["2221", "2229"],
-                        &["223", "229"],
-                        &["23", "26"],
-                        &["270", "271"],
-                        &["2720"],
-                    ],
-                    gaps: &[4, 8, 12],
-                    lengths: &[16],
-                    code: Code {
-                        name: "CVC",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "american-express",
-                CreditCardType {
-                    nice_type: "American Express",
-                    type_: "american-express",
-                    patterns: &[&["34"], &["37"]],
-                    gaps: &[4, 10],
-                    lengths: &[15],
-                    code: Code {
-                        name: "CID",
-                        size: 4,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "diners-club",
-                CreditCardType {
-                    nice_type: "Diners Club",
-                    type_: "diners-club",
-                    patterns: &[&["300", "305"], &["36"], &["38"], &["39"]],
-                    gaps: &[4, 10],
-                    lengths: &[14, 16, 19],
-                    code: Code {
-                        name: "CVV",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "discover",
-                CreditCardType {
-                    nice_type: "Discover",
-                    type_: "discover",
-                    patterns: &[&["6011"], &["644", "649"], &["65"]],
-                    gaps: &[4, 8, 12],
-                    lengths: &[16, 19],
-                    code: Code {
-                        name: "CID",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "jcb",
-                CreditCardType {
-                    nice_type: "JCB",
-                    type_: "jcb",
-                    patterns: &[&["2131"], &["1800"], &["3528", "3589"]],
-                    gaps: &[4, 8, 12],
-                    lengths: &[16, 17, 18, 19],
-                    code: Code {
-                        name: "CVV",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "unionpay",
-                CreditCardType {
-                    nice_type: "UnionPay",
-                    type_: "unionpay",
-                    patterns: &[
-                        &["620"],
-                        &["62100", "62182"],
-                        &["62184", "62187"],
-                        &["62185", "62197"],
-                        &["62200", "62205"],
-                        &["622010", "622999"],
-                        &["622018"],
-                        &["62207", "62209"],
-                        &["623", "626"],
-                        &["6270"],
-                        &["6272"],
-                        &["6276"],
-                        &["627700", "627779"],
-                        &["627781", "627799"],
-                        &["6282", "6289"],
-                        &["6291"],
-                        &["6292"],
-                        &["810"],
-                        &["8110", "8131"],
-                        &["8132", "8151"],
-                        &["8152", "8163"],
-                        &["8164", "8171"],
-                    ],
-                    gaps: &[4, 8, 12],
-                    lengths: &[14, 15, 16, 17, 18, 19],
-                    code: Code {
-                        name: "CVN",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "maestro",
-                CreditCardType {
-                    nice_type: "Maestro",
-                    type_: "maestro",
-                    patterns: &[
-                        &["493698"],
-                        &["500000", "504174"],
-                        &["504176", "506698"],
-                        &["506779", "508999"],
-                        &["56", "59"],
-                        &["63"],
-                        &["67"],
-                        &["6"],
-                    ],
-                    gaps: &[4, 8, 12],
-                    lengths: &[12, 13, 14, 15, 16, 17, 18, 19],
-                    code: Code {
-                        name: "CVC",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "elo",
-                CreditCardType {
-                    nice_type: "Elo",
-                    type_: "elo",
-                    patterns: &[
-                        &["401178"],
-                        &["401179"],
-                        &["438935"],
-                        &["457631"],
-                        &["457632"],
-                        &["431274"],
-                        &["451416"],
-                        &["457393"],
-                        &["504175"],
-                        &["506699", "506778"],
-                        &["509000", "509999"],
-                        &["627780"],
-                        &["636297"],
-                        &["636368"],
-                        &["650031", "650033"],
-                        &["650035", "650051"],
-                        &["650405", "650439"],
-                        &["650485", "650538"],
-                        &["650541", "650598"],
-                        &["650700", "650718"],
-                        &["650720", "650727"],
-                        &["650901", "650978"],
-                        &["651652", "651679"],
-                        &["655000", "655019"],
-                        &["655021", "655058"],
-                    ],
-                    gaps: &[4, 8, 12],
-                    lengths: &[16],
-                    code: Code {
-                        name: "CVE",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "mir",
-                CreditCardType {
-                    nice_type: "Mir",
-                    type_: "mir",
-                    patterns: &[&["2200", "2204"]],
-                    gaps: &[4, 8, 12],
-                    lengths: &[16, 17, 18, 19],
-                    code: Code {
-                        name: "CVP2",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "hyper",
-                CreditCardType {
-                    nice_type: "Hiper",
-                    type_: "hiper",
-                    patterns: &[
-                        &["637095"],
-                        &["63737423"],
-                        &["63743358"],
-                        &["637568"],
-                        &["637599"],
-                        &["637609"],
-                        &["637612"],
-                    ],
-                    gaps: &[4, 8, 12],
-                    lengths: &[16],
-                    code: Code {
-                        name: "CVC",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-            (
-                "hypercard",
-                CreditCardType {
-                    nice_type: "Hipercard",
-                    type_: "hipercard",
-                    patterns: &[&["606282"]],
-                    gaps: &[4, 8, 12],
-                    lengths: &[16],
-                    code: Code {
-                        name: "CVC",
-                        size: 3,
-                    },
-                    match_strength: 0,
-                },
-            ),
-        ];
-        let card_types = BTreeMap::from(cards);
-
-        CreditCardPool(card_types)
+        CreditCardPool::new()
     }
 }
 
+/// The launch of the Mastercard 2-series, `222100` to `272099`.
+const MASTERCARD_2_SERIES: Date = match Date::new(2017, 1, 1) {
+    Ok(date) => date,
+    Err(_) => panic!("invalid date"),
+};
+
+/// The built-in card types, sorted by brand.
+///
+/// This is the data behind [`CreditCardPool::new`] and [`StaticCardPool::new`](crate::StaticCardPool::new), and can
+/// be used to build custom pools in `const` contexts.
+pub const DEFAULT_CARD_TYPES: &[CreditCardType] = &[
+    CreditCardType {
+        nice_type: "American Express",
+        type_: CardBrand::AmericanExpress,
+        patterns: &[&["34"], &["37"]],
+        validity: &[],
+        ranges: &[
+            CardRange::new(&["34"]).with_funding(FundingType::Charge),
+            CardRange::new(&["37"]).with_funding(FundingType::Charge),
+        ],
+        gaps: &[4, 10],
+        lengths: &[15],
+        code: Code {
+            name: "CID",
+            size: 4,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "Diners Club",
+        type_: CardBrand::DinersClub,
+        patterns: &[&["300", "305"], &["36"], &["38"], &["39"]],
+        validity: &[],
+        ranges: &[],
+        gaps: &[4, 10],
+        lengths: &[14, 16, 19],
+        code: Code {
+            name: "CVV",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "Discover",
+        type_: CardBrand::Discover,
+        patterns: &[&["6011"], &["644", "649"], &["65"]],
+        validity: &[],
+        ranges: &[],
+        gaps: &[4, 8, 12],
+        lengths: &[16, 19],
+        code: Code {
+            name: "CID",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "Elo",
+        type_: CardBrand::Elo,
+        patterns: &[
+            &["401178"],
+            &["401179"],
+            &["438935"],
+            &["457631"],
+            &["457632"],
+            &["431274"],
+            &["451416"],
+            &["457393"],
+            &["504175"],
+            &["506699", "506778"],
+            &["509000", "509999"],
+            &["627780"],
+            &["636297"],
+            &["636368"],
+            &["650031", "650033"],
+            &["650035", "650051"],
+            &["650405", "650439"],
+            &["650485", "650538"],
+            &["650541", "650598"],
+            &["650700", "650718"],
+            &["650720", "650727"],
+            &["650901", "650978"],
+            &["651652", "651679"],
+            &["655000", "655019"],
+            &["655021", "655058"],
+        ],
+        validity: &[],
+        ranges: &[CardRange::new(&["65"]).with_co_badged(&[CardBrand::Discover])],
+        gaps: &[4, 8, 12],
+        lengths: &[16],
+        code: Code {
+            name: "CVE",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "Hiper",
+        type_: CardBrand::Hiper,
+        patterns: &[
+            &["637095"],
+            &["63737423"],
+            &["63743358"],
+            &["637568"],
+            &["637599"],
+            &["637609"],
+            &["637612"],
+        ],
+        validity: &[],
+        ranges: &[],
+        gaps: &[4, 8, 12],
+        lengths: &[16],
+        code: Code {
+            name: "CVC",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "Hipercard",
+        type_: CardBrand::Hipercard,
+        patterns: &[&["606282"]],
+        validity: &[],
+        ranges: &[],
+        gaps: &[4, 8, 12],
+        lengths: &[16],
+        code: Code {
+            name: "CVC",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "JCB",
+        type_: CardBrand::Jcb,
+        patterns: &[&["2131"], &["1800"], &["3528", "3589"]],
+        validity: &[],
+        ranges: &[],
+        gaps: &[4, 8, 12],
+        lengths: &[16, 17, 18, 19],
+        code: Code {
+            name: "CVV",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "Maestro",
+        type_: CardBrand::Maestro,
+        patterns: &[
+            &["493698"],
+            &["500000", "504174"],
+            &["504176", "506698"],
+            &["506779", "508999"],
+            &["56", "59"],
+            &["63"],
+            &["67"],
+            &["6"],
+        ],
+        validity: &[],
+        ranges: &[
+            CardRange::new(&["493698"]).with_funding(FundingType::Debit),
+            CardRange::new(&["500000", "504174"]).with_funding(FundingType::Debit),
+            CardRange::new(&["504176", "506698"]).with_funding(FundingType::Debit),
+            CardRange::new(&["506779", "508999"]).with_funding(FundingType::Debit),
+            CardRange::new(&["56", "59"]).with_funding(FundingType::Debit),
+            CardRange::new(&["6"]).with_funding(FundingType::Debit),
+        ],
+        gaps: &[4, 8, 12],
+        lengths: &[12, 13, 14, 15, 16, 17, 18, 19],
+        code: Code {
+            name: "CVC",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "Mastercard",
+        type_: CardBrand::Mastercard,
+        patterns: &[
+            &["51", "55"],
+            &["2221", "2229"],
+            &["223", "229"],
+            &["23", "26"],
+            &["270", "271"],
+            &["2720"],
+        ],
+        validity: &[
+            PatternValidity::new(&["2221", "2229"]).with_valid_from(MASTERCARD_2_SERIES),
+            PatternValidity::new(&["223", "229"]).with_valid_from(MASTERCARD_2_SERIES),
+            PatternValidity::new(&["23", "26"]).with_valid_from(MASTERCARD_2_SERIES),
+            PatternValidity::new(&["270", "271"]).with_valid_from(MASTERCARD_2_SERIES),
+            PatternValidity::new(&["2720"]).with_valid_from(MASTERCARD_2_SERIES),
+        ],
+        ranges: &[],
+        gaps: &[4, 8, 12],
+        lengths: &[16],
+        code: Code {
+            name: "CVC",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "Mir",
+        type_: CardBrand::Mir,
+        patterns: &[&["2200", "2204"]],
+        validity: &[],
+        ranges: &[],
+        gaps: &[4, 8, 12],
+        lengths: &[16, 17, 18, 19],
+        code: Code {
+            name: "CVP2",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "UnionPay",
+        type_: CardBrand::UnionPay,
+        patterns: &[
+            &["620"],
+            &["62100", "62182"],
+            &["62184", "62187"],
+            &["62185", "62197"],
+            &["62200", "62205"],
+            &["622010", "622999"],
+            &["622018"],
+            &["62207", "62209"],
+            &["623", "626"],
+            &["6270"],
+            &["6272"],
+            &["6276"],
+            &["627700", "627779"],
+            &["627781", "627799"],
+            &["6282", "6289"],
+            &["6291"],
+            &["6292"],
+            &["810"],
+            &["8110", "8131"],
+            &["8132", "8151"],
+            &["8152", "8163"],
+            &["8164", "8171"],
+        ],
+        validity: &[],
+        ranges: &[],
+        gaps: &[4, 8, 12],
+        lengths: &[14, 15, 16, 17, 18, 19],
+        code: Code {
+            name: "CVN",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+    CreditCardType {
+        nice_type: "Visa",
+        type_: CardBrand::Visa,
+        patterns: &[&["4"]],
+        validity: &[],
+        ranges: &[
+            // Visa Electron.
+            CardRange::new(&["4026"]).with_funding(FundingType::Debit),
+            CardRange::new(&["417500"]).with_funding(FundingType::Debit),
+            CardRange::new(&["4405"]).with_funding(FundingType::Debit),
+            CardRange::new(&["4508"]).with_funding(FundingType::Debit),
+            CardRange::new(&["4844"]).with_funding(FundingType::Debit),
+            CardRange::new(&["4913"]).with_funding(FundingType::Debit),
+            CardRange::new(&["4917"]).with_funding(FundingType::Debit),
+            CardRange::new(&["4571"])
+                .with_co_badged(&[CardBrand::Dankort])
+                .with_funding(FundingType::Debit),
+        ],
+        gaps: &[4, 8, 12],
+        lengths: &[16, 18, 19],
+        code: Code {
+            name: "CVV",
+            size: 3,
+        },
+        match_strength: 0,
+    },
+];
+
 /// A credit card type.
 ///
 /// Used in the return value of [`CreditCardPool::get_credit_card_type`] and to insert new card types into the pool.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CreditCardType {
     pub nice_type: &'static str,
-    pub type_: &'static str,
+    pub type_: CardBrand,
     pub patterns: &'static [&'static [&'static str]],
+    /// When patterns are in force, for detection as of a date. See [`PatternValidity`].
+    pub validity: &'static [PatternValidity],
+    /// Parts of the card type with extra information, like co-badged networks. See [`CardRange`].
+    pub ranges: &'static [CardRange],
     pub gaps: &'static [u32],
     pub lengths: &'static [u32],
     pub code: Code,
@@ -408,8 +543,10 @@ impl Default for CreditCardType {
     fn default() -> Self {
         Self {
             nice_type: "Default",
-            type_: "default",
+            type_: CardBrand::Custom("default"),
             patterns: &[&["123456789"]],
+            validity: &[],
+            ranges: &[],
             gaps: &[4],
             lengths: &[16],
             code: Code {
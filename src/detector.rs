@@ -0,0 +1,178 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{
+    card_brand::CardBrand,
+    card_types::{CreditCardPool, CreditCardType},
+    errors::CardTypeError,
+    static_pool::StaticCardPool,
+    utils::{match_strength, matches},
+    validation::luhn_check,
+};
+
+/// Detects the card type of a number typed one digit at a time, for typeahead inputs.
+///
+/// The detector keeps the card types that can still match after every digit. A new digit only checks the remaining
+/// candidates, and backspace goes back to the candidates of the previous digit, so the pool is scanned once. The
+/// card types are the same as the ones of [`CreditCardPool::get_credit_card_type`] for the typed number.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{CardBrand, CreditCardPool, IncrementalDetector};
+///
+/// let pool = CreditCardPool::new();
+/// let mut detector = IncrementalDetector::new(&pool);
+///
+/// detector.push('3').unwrap();
+/// assert!(detector.brand().is_none());
+/// assert_eq!(detector.remaining_digits(), 3);
+///
+/// detector.push('7').unwrap();
+/// assert_eq!(detector.brand(), Some(CardBrand::AmericanExpress));
+/// assert_eq!(detector.max_length(), Some(15));
+///
+/// detector.backspace();
+/// assert_eq!(detector.card_number(), "3");
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncrementalDetector {
+    card_number: String,
+    /// The card types that can still match, after every typed digit. The first entry is the whole pool.
+    candidates: Vec<Vec<CreditCardType>>,
+}
+
+impl IncrementalDetector {
+    /// Creates a detector for the card types of a pool, with no digits typed.
+    #[must_use]
+    pub fn new(pool: &CreditCardPool) -> IncrementalDetector {
+        IncrementalDetector {
+            card_number: String::new(),
+            candidates: vec![pool.card_types().to_vec()],
+        }
+    }
+
+    /// Adds a digit at the end of the number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::InvalidCardNumber`] if `digit` is not an ASCII digit, the number is left unchanged.
+    pub fn push(&mut self, digit: char) -> Result<(), CardTypeError> {
+        if !digit.is_ascii_digit() {
+            return Err(CardTypeError::InvalidCardNumber);
+        }
+
+        self.card_number.push(digit);
+        let mut candidates = Vec::new();
+        for card_type in self.current_candidates() {
+            match match_strength(&self.card_number, card_type, None) {
+                Ok(Some(_)) => candidates.push(card_type.clone()),
+                Ok(None) => {}
+                Err(error) => {
+                    self.card_number.pop();
+                    return Err(error);
+                }
+            }
+        }
+        self.candidates.push(candidates);
+
+        Ok(())
+    }
+
+    /// Adds every character of `digits`, stopping at the first one that is not a digit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::InvalidCardNumber`] if a character is not an ASCII digit, the digits before it are
+    /// kept.
+    pub fn push_str(&mut self, digits: &str) -> Result<(), CardTypeError> {
+        digits.chars().try_for_each(|digit| self.push(digit))
+    }
+
+    /// Removes the last digit and returns it, `None` if the number is empty.
+    pub fn backspace(&mut self) -> Option<char> {
+        let digit = self.card_number.pop()?;
+        self.candidates.pop();
+        Some(digit)
+    }
+
+    /// Removes every digit.
+    pub fn clear(&mut self) {
+        self.card_number.clear();
+        self.candidates.truncate(1);
+    }
+
+    /// Returns the digits typed so far.
+    #[must_use]
+    pub fn card_number(&self) -> &str {
+        &self.card_number
+    }
+
+    /// Returns the card types matching the digits typed so far.
+    #[must_use]
+    pub fn card_types(&self) -> Vec<CreditCardType> {
+        // The candidates were checked when their digit was pushed.
+        StaticCardPool::from_card_types(self.current_candidates())
+            .get_credit_card_type(&self.card_number)
+            .map(Iterator::collect)
+            .unwrap_or_default()
+    }
+
+    /// Returns the brand of the number, `None` while no card type or more than one card type matches.
+    #[must_use]
+    pub fn brand(&self) -> Option<CardBrand> {
+        match self.card_types().as_slice() {
+            [card_type] => Some(card_type.type_),
+            _ => None,
+        }
+    }
+
+    /// Returns the most digits still needed before a single card type matches, `0` once at most one does.
+    ///
+    /// After as many digits as the longest matching pattern, the detection settles on one card type or none.
+    #[must_use]
+    pub fn remaining_digits(&self) -> usize {
+        let card_types = self.card_types();
+        if card_types.len() <= 1 {
+            return 0;
+        }
+
+        card_types
+            .iter()
+            .flat_map(|card_type| card_type.patterns.iter())
+            .filter(|pattern| matches(&self.card_number, pattern).unwrap_or(false))
+            .map(|pattern| pattern[0].len())
+            .max()
+            .unwrap_or(0)
+            .saturating_sub(self.card_number.len())
+    }
+
+    /// Returns the largest length allowed by the matching card types, `None` if no card type matches.
+    #[must_use]
+    pub fn max_length(&self) -> Option<u32> {
+        self.card_types()
+            .iter()
+            .flat_map(|card_type| card_type.lengths.iter().copied())
+            .max()
+    }
+
+    /// Returns `true` if the number can still be a valid number of a matching card type: either more digits are
+    /// allowed, or the number already has an allowed length and passes the Luhn checksum.
+    #[must_use]
+    pub fn can_become_valid(&self) -> bool {
+        let length = self.card_number.len();
+
+        self.card_types()
+            .iter()
+            .flat_map(|card_type| card_type.lengths.iter())
+            .any(|&allowed_length| {
+                let allowed_length = allowed_length as usize;
+                allowed_length > length
+                    || (allowed_length == length && luhn_check(&self.card_number))
+            })
+    }
+
+    fn current_candidates(&self) -> &[CreditCardType] {
+        // The first entry is never removed.
+        self.candidates.last().map_or(&[], Vec::as_slice)
+    }
+}
@@ -0,0 +1,396 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::{
+    card_brand::CardBrand,
+    card_range::CardRange,
+    card_types::{Code, CreditCardPool, CreditCardType},
+    effective::{Date, PatternValidity},
+    errors::CardTypeError,
+    utils::matches,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Identifies the card type data of a pool, to tell which data a result was computed with.
+///
+/// It is a fingerprint of every card type: two pools with the same card types have the same version, and any change
+/// to the data, like a crate upgrade with new ranges, gives a new one. It is stable across runs and platforms, so it
+/// can be logged and stored. It displays as 16 hexadecimal digits.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{CardBrand, CreditCardPool};
+///
+/// let mut pool = CreditCardPool::new();
+/// assert_eq!(pool.data_version(), CreditCardPool::new().data_version());
+///
+/// pool.remove_card_type(CardBrand::Maestro);
+/// assert_ne!(pool.data_version(), CreditCardPool::new().data_version());
+/// assert_eq!(pool.data_version().to_string().len(), 16);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DataVersion(pub u64);
+
+impl fmt::Display for DataVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// FNV-1a over an explicit encoding of the card types, so the version doesn't depend on the platform or on the
+/// layout of the types.
+struct Fingerprint(u64);
+
+impl Fingerprint {
+    fn bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn number(&mut self, number: u64) {
+        self.bytes(&number.to_le_bytes());
+    }
+
+    /// Strings and slices are prefixed with their length, so `["12", "3"]` and `["1", "23"]` differ.
+    fn str(&mut self, s: &str) {
+        self.number(s.len() as u64);
+        self.bytes(s.as_bytes());
+    }
+
+    fn pattern(&mut self, pattern: &[&str]) {
+        self.number(pattern.len() as u64);
+        for bound in pattern {
+            self.str(bound);
+        }
+    }
+
+    fn date(&mut self, date: Option<Date>) {
+        match date {
+            Some(date) => {
+                self.number(1);
+                self.number(u64::from(date.year()));
+                self.number(u64::from(date.month()));
+                self.number(u64::from(date.day()));
+            }
+            None => self.number(0),
+        }
+    }
+
+    fn card_type(&mut self, card_type: &CreditCardType) {
+        self.str(card_type.nice_type);
+        self.str(card_type.type_.as_str());
+
+        self.number(card_type.patterns.len() as u64);
+        for pattern in card_type.patterns {
+            self.pattern(pattern);
+        }
+
+        self.number(card_type.validity.len() as u64);
+        for validity in card_type.validity {
+            self.pattern(validity.pattern);
+            self.date(validity.valid_from);
+            self.date(validity.valid_until);
+        }
+
+        self.number(card_type.ranges.len() as u64);
+        for range in card_type.ranges {
+            self.pattern(range.pattern);
+            self.number(range.co_badged.len() as u64);
+            for brand in range.co_badged {
+                self.str(brand.as_str());
+            }
+            self.str(range.funding.as_str());
+            self.str(range.kind.as_str());
+        }
+
+        for numbers in [card_type.gaps, card_type.lengths] {
+            self.number(numbers.len() as u64);
+            for number in numbers {
+                self.number(u64::from(*number));
+            }
+        }
+
+        self.str(card_type.code.name);
+        self.number(u64::from(card_type.code.size));
+        self.number(u64::from(card_type.match_strength));
+    }
+}
+
+/// An old and a new value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    /// Returns the change, `None` if the values are equal.
+    fn of(old: T, new: T) -> Option<Change<T>> {
+        (old != new).then_some(Change { old, new })
+    }
+}
+
+/// The changes to a card type present in both pools, `None` for the fields that didn't change.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CardTypeChange {
+    /// The brand of the card type.
+    pub brand: CardBrand,
+    pub nice_type: Option<Change<&'static str>>,
+    pub patterns: Option<Change<&'static [&'static [&'static str]]>>,
+    pub validity: Option<Change<&'static [PatternValidity]>>,
+    pub ranges: Option<Change<&'static [CardRange]>>,
+    pub gaps: Option<Change<&'static [u32]>>,
+    pub lengths: Option<Change<&'static [u32]>>,
+    pub code: Option<Change<Code>>,
+}
+
+/// The differences between two pools, returned by [`CreditCardPool::diff`].
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{CardBrand, CreditCardPoolBuilder, Preset};
+///
+/// let old = CreditCardPoolBuilder::preset(Preset::Major).build().unwrap();
+/// let new = CreditCardPoolBuilder::preset(Preset::Major)
+///     .disable(CardBrand::Discover)
+///     .enable(CardBrand::Jcb)
+///     .lengths(CardBrand::Visa, &[16])
+///     .build()
+///     .unwrap();
+///
+/// let diff = old.diff(&new);
+///
+/// assert_eq!(diff.added, [CardBrand::Jcb]);
+/// assert_eq!(diff.removed, [CardBrand::Discover]);
+/// assert_eq!(diff.changed.len(), 1);
+/// assert_eq!(diff.changed[0].brand, CardBrand::Visa);
+/// assert_eq!(diff.changed[0].lengths.unwrap().new, [16]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PoolDiff {
+    /// The brands only in the new pool.
+    pub added: Vec<CardBrand>,
+    /// The brands only in the old pool.
+    pub removed: Vec<CardBrand>,
+    /// The card types in both pools that changed.
+    pub changed: Vec<CardTypeChange>,
+}
+
+impl PoolDiff {
+    /// Returns `true` if the pools have the same card types.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A prefix whose card numbers are detected differently by two pools, returned by
+/// [`CreditCardPool::reclassified_prefixes`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Reclassification {
+    /// The IIN prefix, every card number starting with it is detected the same way.
+    pub prefix: String,
+    /// The brands detected by the old pool, empty if no card type matches.
+    pub old: Vec<CardBrand>,
+    /// The brands detected by the new pool.
+    pub new: Vec<CardBrand>,
+}
+
+impl CreditCardPool {
+    /// Returns the version of the card types of the pool, see [`DataVersion`].
+    #[must_use]
+    pub fn data_version(&self) -> DataVersion {
+        let mut fingerprint = Fingerprint(FNV_OFFSET_BASIS);
+        fingerprint.number(self.card_types().len() as u64);
+        for card_type in self.card_types() {
+            fingerprint.card_type(card_type);
+        }
+
+        DataVersion(fingerprint.0)
+    }
+
+    /// Compares the pool with a `new` one, by brand.
+    #[must_use]
+    pub fn diff(&self, new: &CreditCardPool) -> PoolDiff {
+        let mut diff = PoolDiff::default();
+
+        for old_type in self.card_types() {
+            let Some(new_type) = new.card_type(old_type.type_) else {
+                diff.removed.push(old_type.type_);
+                continue;
+            };
+            if old_type == new_type {
+                continue;
+            }
+
+            diff.changed.push(CardTypeChange {
+                brand: old_type.type_,
+                nice_type: Change::of(old_type.nice_type, new_type.nice_type),
+                patterns: Change::of(old_type.patterns, new_type.patterns),
+                validity: Change::of(old_type.validity, new_type.validity),
+                ranges: Change::of(old_type.ranges, new_type.ranges),
+                gaps: Change::of(old_type.gaps, new_type.gaps),
+                lengths: Change::of(old_type.lengths, new_type.lengths),
+                code: Change::of(old_type.code.clone(), new_type.code.clone()),
+            });
+        }
+
+        diff.added = new
+            .card_types()
+            .iter()
+            .map(|card_type| card_type.type_)
+            .filter(|brand| self.card_type(*brand).is_none())
+            .collect();
+
+        diff
+    }
+
+    /// Returns the IIN prefixes whose card numbers would be detected as other brands by the `new` pool.
+    ///
+    /// Prefixes are as short as possible: every card number starting with a prefix is detected the same way by each
+    /// pool, and ten prefixes with the same change are merged into their common prefix. Changes that don't affect
+    /// detection, like gaps or lengths, give no prefixes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::{CardBrand, CreditCardPool, CreditCardType, DEFAULT_CARD_TYPES};
+    ///
+    /// let amex = DEFAULT_CARD_TYPES
+    ///     .iter()
+    ///     .find(|card_type| card_type.type_ == CardBrand::AmericanExpress)
+    ///     .unwrap();
+    ///
+    /// let old = CreditCardPool::new();
+    /// let mut new = CreditCardPool::new();
+    /// new.insert_card_type(CreditCardType {
+    ///     patterns: &[&["34"]],
+    ///     ..amex.clone()
+    /// });
+    ///
+    /// let reclassifications = old.reclassified_prefixes(&new).unwrap();
+    ///
+    /// assert_eq!(reclassifications.len(), 1);
+    /// assert_eq!(reclassifications[0].prefix, "37");
+    /// assert_eq!(reclassifications[0].old, [CardBrand::AmericanExpress]);
+    /// assert!(reclassifications[0].new.is_empty());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pattern of either pool is malformed.
+    pub fn reclassified_prefixes(
+        &self,
+        new: &CreditCardPool,
+    ) -> Result<Vec<Reclassification>, CardTypeError> {
+        let mut reclassifications = Vec::new();
+        let mut prefix = String::new();
+
+        for digit in '0'..='9' {
+            prefix.push(digit);
+            reclassify(self, new, &mut prefix, &mut reclassifications)?;
+            prefix.pop();
+        }
+
+        Ok(reclassifications)
+    }
+
+    fn card_type(&self, brand: CardBrand) -> Option<&CreditCardType> {
+        self.find(brand).ok().map(|index| &self.card_types()[index])
+    }
+}
+
+/// Pushes the reclassified prefixes starting with `prefix`.
+fn reclassify(
+    old: &CreditCardPool,
+    new: &CreditCardPool,
+    prefix: &mut String,
+    reclassifications: &mut Vec<Reclassification>,
+) -> Result<(), CardTypeError> {
+    let old_candidates = candidates(old, prefix)?;
+    let new_candidates = candidates(new, prefix)?;
+    // Detection only looks at the card types with a pattern matching the number.
+    if old_candidates == new_candidates {
+        return Ok(());
+    }
+
+    // Once the prefix covers every matching pattern, longer numbers are detected like the prefix.
+    let is_settled = old_candidates
+        .iter()
+        .chain(&new_candidates)
+        .flat_map(|(_, _, patterns)| patterns)
+        .all(|pattern| pattern[0].len() <= prefix.len());
+    if is_settled {
+        let old_brands = brands(old, prefix)?;
+        let new_brands = brands(new, prefix)?;
+        if old_brands != new_brands {
+            reclassifications.push(Reclassification {
+                prefix: prefix.clone(),
+                old: old_brands,
+                new: new_brands,
+            });
+        }
+        return Ok(());
+    }
+
+    let start = reclassifications.len();
+    for digit in '0'..='9' {
+        prefix.push(digit);
+        reclassify(old, new, prefix, reclassifications)?;
+        prefix.pop();
+    }
+
+    let children = &reclassifications[start..];
+    let is_uniform = children.len() == 10
+        && children.iter().all(|child| {
+            child.prefix.len() == prefix.len() + 1
+                && child.old == children[0].old
+                && child.new == children[0].new
+        });
+    if is_uniform {
+        let merged = Reclassification {
+            prefix: prefix.clone(),
+            ..children[0].clone()
+        };
+        reclassifications.truncate(start);
+        reclassifications.push(merged);
+    }
+
+    Ok(())
+}
+
+type Candidate = (CardBrand, u32, Vec<&'static [&'static str]>);
+
+/// Returns the brands and match strengths of the card types with a pattern matching `prefix`, with their matching
+/// patterns.
+fn candidates(pool: &CreditCardPool, prefix: &str) -> Result<Vec<Candidate>, CardTypeError> {
+    let mut candidates = Vec::new();
+
+    for card_type in pool.card_types() {
+        let mut patterns = Vec::new();
+        for pattern in card_type.patterns {
+            if matches(prefix, pattern)? {
+                patterns.push(*pattern);
+            }
+        }
+        if !patterns.is_empty() {
+            candidates.push((card_type.type_, card_type.match_strength, patterns));
+        }
+    }
+
+    Ok(candidates)
+}
+
+fn brands(pool: &CreditCardPool, prefix: &str) -> Result<Vec<CardBrand>, CardTypeError> {
+    Ok(pool
+        .get_credit_card_type(prefix)?
+        .iter()
+        .map(|card_type| card_type.type_)
+        .collect())
+}
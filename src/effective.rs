@@ -0,0 +1,244 @@
+use core::{fmt, str::FromStr};
+
+use crate::{card_types::CreditCardType, errors::CardTypeError};
+
+/// A calendar date, used to detect card types with the patterns in force at the time.
+///
+/// Dates are ordered chronologically and display as `YYYY-MM-DD`. A date always exists, [`Date::new`] and parsing
+/// check it.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::Date;
+///
+/// let date: Date = "2017-01-01".parse().unwrap();
+///
+/// assert_eq!(date, Date::new(2017, 1, 1).unwrap());
+/// assert!(date > "2016-12-31".parse().unwrap());
+/// assert_eq!(date.to_string(), "2017-01-01");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::InvalidDate`] if the month or the day doesn't exist.
+    pub const fn new(year: u16, month: u8, day: u8) -> Result<Date, CardTypeError> {
+        let is_leap_year =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return Err(CardTypeError::InvalidDate),
+        };
+        if day == 0 || day > days_in_month {
+            return Err(CardTypeError::InvalidDate);
+        }
+
+        Ok(Date { year, month, day })
+    }
+
+    /// Returns the year, e.g. `2017`.
+    #[must_use]
+    pub const fn year(self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    #[must_use]
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1.
+    #[must_use]
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+}
+
+impl FromStr for Date {
+    type Err = CardTypeError;
+
+    /// Parses the `YYYY-MM-DD` format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = |start: usize, end: usize| {
+            s.get(start..end)
+                .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_digit()))
+                .and_then(|digits| digits.parse::<u16>().ok())
+                .ok_or(CardTypeError::InvalidDate)
+        };
+        if s.len() != 10 || s.as_bytes()[4] != b'-' || s.as_bytes()[7] != b'-' {
+            return Err(CardTypeError::InvalidDate);
+        }
+
+        let month = u8::try_from(digits(5, 7)?).map_err(|_| CardTypeError::InvalidDate)?;
+        let day = u8::try_from(digits(8, 10)?).map_err(|_| CardTypeError::InvalidDate)?;
+        Date::new(digits(0, 4)?, month, day)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The dates a pattern of a card type is in force, for detection as of a date.
+///
+/// The `pattern` is one of the [`patterns`](CreditCardType::patterns) of the card type, both dates are inclusive and
+/// `None` leaves that side open. A pattern without validity is always in force, and detection without a date uses
+/// every pattern. [`CreditCardPool::insert_card_type`](crate::CreditCardPool::insert_card_type) panics on a validity of a pattern the card type doesn't have,
+/// or ending before it starts.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use credit_card_types::{CardBrand, CreditCardPool, CreditCardType, Date, PatternValidity, DEFAULT_CARD_TYPES};
+///
+/// const END_OF_2019: Date = match Date::new(2019, 12, 31) {
+///     Ok(date) => date,
+///     Err(_) => panic!("invalid date"),
+/// };
+///
+/// // A range moved from one card type to another at the end of 2019.
+/// const VALIDITY: &[PatternValidity] =
+///     &[PatternValidity::new(&["6500", "6509"]).with_valid_until(END_OF_2019)];
+///
+/// let discover = DEFAULT_CARD_TYPES
+///     .iter()
+///     .find(|card_type| card_type.type_ == CardBrand::Discover)
+///     .unwrap();
+///
+/// let mut pool = CreditCardPool::new_empty();
+/// pool.insert_card_type(CreditCardType {
+///     patterns: &[&["6011"], &["6500", "6509"]],
+///     validity: VALIDITY,
+///     ..discover.clone()
+/// });
+///
+/// let in_2019 = "2019-06-01".parse().unwrap();
+/// let in_2020 = "2020-06-01".parse().unwrap();
+///
+/// assert_eq!(pool.get_credit_card_type_on("6500000000000000", in_2019).unwrap().len(), 1);
+/// assert!(pool.get_credit_card_type_on("6500000000000000", in_2020).unwrap().is_empty());
+/// # }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PatternValidity {
+    /// A pattern of the card type, like `&["2221", "2229"]`.
+    pub pattern: &'static [&'static str],
+    /// The first day the pattern is in force.
+    pub valid_from: Option<Date>,
+    /// The last day the pattern is in force.
+    pub valid_until: Option<Date>,
+}
+
+impl PatternValidity {
+    /// Creates a validity without dates, see the `with_*` methods.
+    #[must_use]
+    pub const fn new(pattern: &'static [&'static str]) -> PatternValidity {
+        PatternValidity {
+            pattern,
+            valid_from: None,
+            valid_until: None,
+        }
+    }
+
+    /// Sets the first day the pattern is in force.
+    #[must_use]
+    pub const fn with_valid_from(self, valid_from: Date) -> PatternValidity {
+        PatternValidity {
+            valid_from: Some(valid_from),
+            ..self
+        }
+    }
+
+    /// Sets the last day the pattern is in force.
+    #[must_use]
+    pub const fn with_valid_until(self, valid_until: Date) -> PatternValidity {
+        PatternValidity {
+            valid_until: Some(valid_until),
+            ..self
+        }
+    }
+
+    /// Returns `true` if `date` is between the dates.
+    #[must_use]
+    pub fn contains(&self, date: Date) -> bool {
+        self.valid_from.is_none_or(|valid_from| valid_from <= date)
+            && self
+                .valid_until
+                .is_none_or(|valid_until| date <= valid_until)
+    }
+}
+
+impl CreditCardType {
+    /// Returns `true` if `pattern` is in force on `date`, or on any date for `None`.
+    ///
+    /// A pattern is in force when every [validity](CreditCardType::validity) declared for it contains the date.
+    #[must_use]
+    pub fn is_in_force(&self, pattern: &[&str], date: Option<Date>) -> bool {
+        let Some(date) = date else {
+            return true;
+        };
+
+        self.validity
+            .iter()
+            .filter(|validity| validity.pattern == pattern)
+            .all(|validity| validity.contains(date))
+    }
+
+    /// Returns the patterns in force on `date`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::{CardBrand, Date, DEFAULT_CARD_TYPES};
+    ///
+    /// let mastercard = DEFAULT_CARD_TYPES
+    ///     .iter()
+    ///     .find(|card_type| card_type.type_ == CardBrand::Mastercard)
+    ///     .unwrap();
+    ///
+    /// let date = Date::new(2016, 6, 1).unwrap();
+    /// assert_eq!(mastercard.patterns_on(date).collect::<Vec<_>>(), [&["51", "55"]]);
+    /// ```
+    pub fn patterns_on(&self, date: Date) -> impl Iterator<Item = &'static [&'static str]> + '_ {
+        self.patterns
+            .iter()
+            .copied()
+            .filter(move |pattern| self.is_in_force(pattern, Some(date)))
+    }
+
+    /// Checks that every validity is of one of the patterns and doesn't end before it starts.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn check_validity(&self) -> Result<(), &'static str> {
+        for validity in self.validity {
+            if !self.patterns.contains(&validity.pattern) {
+                return Err("validity of an unknown pattern");
+            }
+            if let (Some(valid_from), Some(valid_until)) =
+                (validity.valid_from, validity.valid_until)
+            {
+                if valid_from > valid_until {
+                    return Err("validity ending before it starts");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,322 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    aid::AidRegistry,
+    card_brand::CardBrand,
+    card_types::CreditCardPool,
+    errors::CardTypeError,
+    pan::{zeroize_string, Pan},
+    track::{Expiry, Track2},
+};
+
+/// Application Primary Account Number.
+const TAG_PAN: u32 = 0x5A;
+/// Track 2 Equivalent Data.
+const TAG_TRACK2: u32 = 0x57;
+/// Application Expiration Date.
+const TAG_EXPIRY: u32 = 0x5F24;
+/// Application Identifier (AID) - terminal.
+const TAG_AID_TERMINAL: u32 = 0x9F06;
+/// Application Identifier (AID) - card.
+const TAG_AID_CARD: u32 = 0x4F;
+
+/// The deepest nesting of constructed data objects that is decoded. EMV templates are only nested a few levels deep,
+/// the limit keeps crafted data from exhausting the stack.
+const MAX_NESTING: usize = 8;
+
+/// A BER-TLV data object of EMV data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tlv<'a> {
+    /// The tag, e.g. `0x5A` or `0x9F06`.
+    pub tag: u32,
+    /// The value, the encoded data objects of a constructed tag.
+    pub value: &'a [u8],
+}
+
+impl<'a> Tlv<'a> {
+    /// Returns an iterator over the data objects of `data`, without going into constructed ones. Padding bytes
+    /// `0x00` and `0xFF` between data objects are skipped.
+    #[must_use]
+    pub fn iter(data: &'a [u8]) -> TlvIter<'a> {
+        TlvIter { data }
+    }
+
+    /// Returns `true` if the value holds other data objects, like the `70` and `77` templates.
+    #[must_use]
+    pub fn is_constructed(&self) -> bool {
+        // The first byte of a tag is at most 0xFF, shifted by the number of following bytes.
+        let mut first_byte = self.tag;
+        while first_byte > 0xFF {
+            first_byte >>= 8;
+        }
+        first_byte & 0x20 != 0
+    }
+}
+
+/// Iterator over the data objects of EMV data, returned by [`Tlv::iter`].
+///
+/// A malformed data object ends the iteration with an error.
+#[derive(Debug, Clone)]
+pub struct TlvIter<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for TlvIter<'a> {
+    type Item = Result<Tlv<'a>, CardTypeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let [0x00 | 0xFF, rest @ ..] = self.data {
+            self.data = rest;
+        }
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let tlv = parse_tlv(self.data);
+        match tlv {
+            Ok((tlv, rest)) => {
+                self.data = rest;
+                Some(Ok(tlv))
+            }
+            Err(error) => {
+                self.data = &[];
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+fn parse_tlv(data: &[u8]) -> Result<(Tlv<'_>, &[u8]), CardTypeError> {
+    let truncated = CardTypeError::InvalidEmvData("truncated data object");
+
+    let (&first_byte, mut data) = data.split_first().ok_or(truncated.clone())?;
+    let mut tag = u32::from(first_byte);
+    if first_byte & 0x1F == 0x1F {
+        loop {
+            let (&byte, rest) = data.split_first().ok_or(truncated.clone())?;
+            if tag > 0x00FF_FFFF {
+                return Err(CardTypeError::InvalidEmvData("tag longer than 4 bytes"));
+            }
+            tag = tag << 8 | u32::from(byte);
+            data = rest;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+    }
+
+    let (&length_byte, mut data) = data.split_first().ok_or(truncated.clone())?;
+    let length = match length_byte {
+        0x00..=0x7F => usize::from(length_byte),
+        0x81..=0x84 => {
+            let length_bytes = data
+                .get(..usize::from(length_byte & 0x7F))
+                .ok_or(truncated.clone())?;
+            data = &data[length_bytes.len()..];
+            length_bytes
+                .iter()
+                .fold(0, |length, byte| length << 8 | usize::from(*byte))
+        }
+        _ => return Err(CardTypeError::InvalidEmvData("invalid length")),
+    };
+
+    let value = data.get(..length).ok_or(truncated)?;
+    Ok((Tlv { tag, value }, &data[length..]))
+}
+
+/// The card data of an EMV chip transaction.
+///
+/// The data objects are searched in the templates too, the first one of each tag is used.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{BrandCheck, CardBrand, CreditCardPool, EmvData};
+///
+/// let data = [
+///     0x9F, 0x06, 0x07, 0xA0, 0x00, 0x00, 0x00, 0x03, 0x10, 0x10, // AID
+///     0x5A, 0x08, 0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, // PAN
+///     0x5F, 0x24, 0x03, 0x25, 0x12, 0x31, // Expiry date
+/// ];
+///
+/// let emv_data = EmvData::parse(&data).unwrap();
+/// assert_eq!(emv_data.pan().unwrap().last_four(), "1111");
+/// assert_eq!(emv_data.aid_network(), Some(CardBrand::Visa));
+///
+/// let pool = CreditCardPool::new();
+/// assert_eq!(emv_data.check_brand(&pool).unwrap(), BrandCheck::Consistent(CardBrand::Visa));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EmvData {
+    /// The card number of tag `5A`.
+    pub pan: Option<Pan>,
+    /// The Track 2 equivalent data of tag `57`.
+    pub track2: Option<Track2>,
+    /// The expiry date of tag `5F24`.
+    pub expiry: Option<Expiry>,
+    /// The Application Identifier of tag `9F06`, or `4F` if absent.
+    pub aid: Option<Vec<u8>>,
+}
+
+impl EmvData {
+    /// Decodes EMV data, like the TLV data of an authorization request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::InvalidEmvData`] if a data object or a value is malformed, and the errors of
+    /// [`Track2::parse`] for malformed Track 2 equivalent data.
+    pub fn parse(data: &[u8]) -> Result<EmvData, CardTypeError> {
+        let mut emv_data = EmvData::default();
+        let mut card_aid = None;
+        emv_data.read(data, &mut card_aid, 0)?;
+
+        if emv_data.aid.is_none() {
+            emv_data.aid = card_aid;
+        }
+        Ok(emv_data)
+    }
+
+    fn read(
+        &mut self,
+        data: &[u8],
+        card_aid: &mut Option<Vec<u8>>,
+        depth: usize,
+    ) -> Result<(), CardTypeError> {
+        if depth > MAX_NESTING {
+            return Err(CardTypeError::InvalidEmvData("nesting too deep"));
+        }
+
+        for tlv in Tlv::iter(data) {
+            let tlv = tlv?;
+
+            match tlv.tag {
+                _ if tlv.is_constructed() => self.read(tlv.value, card_aid, depth + 1)?,
+                TAG_PAN if self.pan.is_none() => {
+                    let pan = decode_bcd(tlv.value)
+                        .ok_or(CardTypeError::InvalidEmvData("invalid PAN"))?;
+                    self.pan = Some(Pan::new(pan)?);
+                }
+                TAG_TRACK2 if self.track2.is_none() => {
+                    let data = decode_bcd(tlv.value).ok_or(CardTypeError::InvalidEmvData(
+                        "invalid Track 2 equivalent data",
+                    ))?;
+                    let track2 = Track2::parse(&data);
+                    zeroize_string(data);
+                    self.track2 = Some(track2?);
+                }
+                TAG_EXPIRY if self.expiry.is_none() => {
+                    let expiry = decode_bcd(tlv.value)
+                        .filter(|expiry| expiry.len() == 6)
+                        .ok_or(CardTypeError::InvalidEmvData("invalid expiry date"))?;
+                    self.expiry = Some(
+                        Expiry::from_yymm(&expiry[..4])
+                            .map_err(|_| CardTypeError::InvalidEmvData("invalid expiry date"))?,
+                    );
+                }
+                TAG_AID_TERMINAL if self.aid.is_none() => self.aid = Some(tlv.value.to_vec()),
+                TAG_AID_CARD if card_aid.is_none() => *card_aid = Some(tlv.value.to_vec()),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the card number of tag `5A`, or of the Track 2 equivalent data if absent.
+    #[must_use]
+    pub fn pan(&self) -> Option<&Pan> {
+        self.pan
+            .as_ref()
+            .or_else(|| self.track2.as_ref().map(|track2| &track2.pan))
+    }
+
+    /// Returns the network of the AID in the default [`AidRegistry`], `None` if there is no AID or its network is
+    /// unknown.
+    #[must_use]
+    pub fn aid_network(&self) -> Option<CardBrand> {
+        AidRegistry::new().brand(self.aid.as_deref()?)
+    }
+
+    /// Compares the network of the AID with the card type of the card number.
+    ///
+    /// The brands are consistent if the AID network is the brand of the card type, or one of the networks the card
+    /// is co-badged with.
+    ///
+    /// The AID is looked up in the default [`AidRegistry`], see [`EmvData::check_brand_with`] for custom AIDs.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreditCardPool::get_card_matches`].
+    pub fn check_brand(&self, pool: &CreditCardPool) -> Result<BrandCheck, CardTypeError> {
+        self.check_brand_with(pool, &AidRegistry::new())
+    }
+
+    /// Compares the network of the AID in `registry` with the card type of the card number, see
+    /// [`EmvData::check_brand`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreditCardPool::get_card_matches`].
+    pub fn check_brand_with(
+        &self,
+        pool: &CreditCardPool,
+        registry: &AidRegistry,
+    ) -> Result<BrandCheck, CardTypeError> {
+        let aid_network = self.aid.as_deref().and_then(|aid| registry.brand(aid));
+        let (Some(pan), Some(aid_network)) = (self.pan(), aid_network) else {
+            return Ok(BrandCheck::Unknown);
+        };
+
+        let card_matches = pool.get_card_matches(pan)?;
+        let [card_match] = card_matches.as_slice() else {
+            return Ok(BrandCheck::Unknown);
+        };
+
+        if card_match.networks().any(|network| network == aid_network) {
+            Ok(BrandCheck::Consistent(aid_network))
+        } else {
+            Ok(BrandCheck::Mismatch {
+                detected: card_match.primary(),
+                aid_network,
+            })
+        }
+    }
+}
+
+/// The result of [`EmvData::check_brand`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BrandCheck {
+    /// The card number and the AID are of this network.
+    Consistent(CardBrand),
+    /// The card number and the AID are of different networks.
+    Mismatch {
+        /// The brand of the card type of the card number.
+        detected: CardBrand,
+        /// The network of the AID.
+        aid_network: CardBrand,
+    },
+    /// The card number or the AID is missing, or their network is not known.
+    Unknown,
+}
+
+/// Decodes packed BCD digits, the separator `D` of Track 2 equivalent data becomes `=` and the trailing `F` padding
+/// is removed. `None` for any other nibble, or digits after the padding.
+fn decode_bcd(bytes: &[u8]) -> Option<String> {
+    let mut digits = String::with_capacity(bytes.len() * 2);
+    let mut padding = false;
+
+    for nibble in bytes.iter().flat_map(|byte| [byte >> 4, byte & 0x0F]) {
+        match nibble {
+            0x0F => padding = true,
+            0..=9 if !padding => digits.push(char::from(b'0' + nibble)),
+            0x0D if !padding => digits.push('='),
+            _ => {
+                // The digits may be part of a card number.
+                zeroize_string(digits);
+                return None;
+            }
+        }
+    }
+
+    Some(digits)
+}
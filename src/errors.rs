@@ -1,35 +1,96 @@
+use crate::card_brand::CardBrand;
+
 #[derive(Debug, Clone)]
 pub enum CardTypeError {
-    ParseIntError(std::num::ParseIntError),
-    TryFromIntError(std::num::TryFromIntError),
+    ParseIntError(core::num::ParseIntError),
+    TryFromIntError(core::num::TryFromIntError),
+    UnknownCardBrand,
+    InvalidCardNumber,
+    InvalidCardType(CardBrand, &'static str),
+    InvalidTrackData(&'static str),
+    InvalidEmvData(&'static str),
+    InvalidIso8583Field(&'static str),
+    InvalidDate,
 }
 
-impl std::fmt::Display for CardTypeError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for CardTypeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
             CardTypeError::ParseIntError(err) => write!(f, "{err}"),
             CardTypeError::TryFromIntError(err) => write!(f, "{err}"),
+            CardTypeError::UnknownCardBrand => write!(f, "unknown card brand"),
+            CardTypeError::InvalidCardNumber => write!(f, "invalid card number"),
+            CardTypeError::InvalidCardType(brand, reason) => {
+                write!(f, "invalid card type `{brand}`: {reason}")
+            }
+            CardTypeError::InvalidTrackData(reason) => write!(f, "invalid track data: {reason}"),
+            CardTypeError::InvalidEmvData(reason) => write!(f, "invalid EMV data: {reason}"),
+            CardTypeError::InvalidIso8583Field(reason) => {
+                write!(f, "invalid ISO 8583 field: {reason}")
+            }
+            CardTypeError::InvalidDate => write!(f, "invalid date"),
         }
     }
 }
 
-impl From<std::num::ParseIntError> for CardTypeError {
-    fn from(err: std::num::ParseIntError) -> Self {
+impl From<core::num::ParseIntError> for CardTypeError {
+    fn from(err: core::num::ParseIntError) -> Self {
         CardTypeError::ParseIntError(err)
     }
 }
 
-impl From<std::num::TryFromIntError> for CardTypeError {
-    fn from(err: std::num::TryFromIntError) -> Self {
+impl From<core::num::TryFromIntError> for CardTypeError {
+    fn from(err: core::num::TryFromIntError) -> Self {
         CardTypeError::TryFromIntError(err)
     }
 }
 
-impl std::error::Error for CardTypeError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for CardTypeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             CardTypeError::ParseIntError(err) => Some(err),
             CardTypeError::TryFromIntError(err) => Some(err),
+            CardTypeError::UnknownCardBrand
+            | CardTypeError::InvalidCardNumber
+            | CardTypeError::InvalidCardType(..)
+            | CardTypeError::InvalidTrackData(_)
+            | CardTypeError::InvalidEmvData(_)
+            | CardTypeError::InvalidIso8583Field(_)
+            | CardTypeError::InvalidDate => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum BatchError {
+    Io(std::io::Error),
+    MissingColumn(alloc::string::String),
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for BatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BatchError::Io(err) => write!(f, "{err}"),
+            BatchError::MissingColumn(column) => write!(f, "missing column `{column}`"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for BatchError {
+    fn from(err: std::io::Error) -> Self {
+        BatchError::Io(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BatchError::Io(err) => Some(err),
+            BatchError::MissingColumn(_) => None,
         }
     }
 }
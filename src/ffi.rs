@@ -0,0 +1,499 @@
+//! C bindings, enabled by the `ffi` feature.
+//!
+//! The header is `include/credit_card_types.h`, generated with `cbindgen --config cbindgen.toml --output
+//! include/credit_card_types.h`. Build the library to link against with `cargo rustc --lib --features ffi
+//! --crate-type staticlib`, or `cdylib`.
+//!
+//! All functions return one of the `CCT_*` status codes. Detection results are copied into [`CctCardType`] structs
+//! with inline buffers, so they stay valid after the pool is freed.
+//!
+//! ```c
+//! CctPool *pool = cct_pool_new();
+//!
+//! CctCardType matches[4];
+//! size_t count = 0;
+//! if (cct_pool_detect(pool, "4111111111111111", matches, 4, &count) == CCT_OK && count == 1) {
+//!     printf("%s\n", matches[0].brand);
+//! }
+//!
+//! cct_pool_free(pool);
+//! ```
+
+#![allow(clippy::missing_safety_doc)]
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    ffi::{c_char, CStr},
+    ptr, slice,
+};
+
+use crate::{
+    card_brand::CardBrand,
+    card_types::{Code, CreditCardPool, CreditCardType},
+};
+
+/// The call succeeded.
+pub const CCT_OK: i32 = 0;
+/// A required pointer argument was null.
+pub const CCT_ERROR_NULL_POINTER: i32 = -1;
+/// A string argument was not valid UTF-8.
+pub const CCT_ERROR_INVALID_UTF8: i32 = -2;
+/// The card number contained something other than digits.
+pub const CCT_ERROR_INVALID_CARD_NUMBER: i32 = -3;
+/// The output buffer was too small, `out_count` holds the number of matches.
+pub const CCT_ERROR_BUFFER_TOO_SMALL: i32 = -4;
+/// The card type to insert was invalid, or did not fit in a [`CctCardType`].
+pub const CCT_ERROR_INVALID_CARD_TYPE: i32 = -5;
+
+/// Size of [`CctCardType::brand`], including the terminating NUL.
+pub const CCT_BRAND_LEN: usize = 32;
+/// Size of [`CctCardType::nice_type`], including the terminating NUL.
+pub const CCT_NICE_TYPE_LEN: usize = 64;
+/// Size of [`CctCardType::code_name`], including the terminating NUL.
+pub const CCT_CODE_NAME_LEN: usize = 16;
+/// Capacity of [`CctCardType::gaps`].
+pub const CCT_MAX_GAPS: usize = 8;
+/// Capacity of [`CctCardType::lengths`].
+pub const CCT_MAX_LENGTHS: usize = 16;
+
+/// An opaque pool of card types, created with `cct_pool_new` and freed with `cct_pool_free`.
+pub struct CctPool {
+    // Dropped before `storage`, which owns the data of the card types inserted with `cct_pool_insert_card_type`.
+    pool: CreditCardPool,
+    // The data of each inserted card type by brand, freed when the card type is replaced or removed.
+    storage: Vec<(CardBrand, Storage)>,
+}
+
+/// The data of a card type inserted with `cct_pool_insert_card_type`.
+#[derive(Default)]
+struct Storage {
+    strings: Vec<Box<str>>,
+    patterns: Vec<Box<[&'static str]>>,
+    pattern_lists: Vec<Box<[&'static [&'static str]]>>,
+    numbers: Vec<Box<[u32]>>,
+}
+
+impl Storage {
+    fn string(&mut self, value: String) -> &'static str {
+        let value = value.into_boxed_str();
+        // SAFETY: the box is never moved out, and only dropped once the card type using the reference is no longer in
+        // the pool, see `CctPool`.
+        let reference = unsafe { &*ptr::from_ref::<str>(&value) };
+        self.strings.push(value);
+        reference
+    }
+
+    fn pattern(&mut self, value: Vec<&'static str>) -> &'static [&'static str] {
+        let value = value.into_boxed_slice();
+        // SAFETY: see `Storage::string`.
+        let reference = unsafe { &*ptr::from_ref::<[&'static str]>(&value) };
+        self.patterns.push(value);
+        reference
+    }
+
+    fn pattern_list(
+        &mut self,
+        value: Vec<&'static [&'static str]>,
+    ) -> &'static [&'static [&'static str]] {
+        let value = value.into_boxed_slice();
+        // SAFETY: see `Storage::string`.
+        let reference = unsafe { &*ptr::from_ref::<[&'static [&'static str]]>(&value) };
+        self.pattern_lists.push(value);
+        reference
+    }
+
+    fn numbers(&mut self, value: &[u32]) -> &'static [u32] {
+        let value = Box::<[u32]>::from(value);
+        // SAFETY: see `Storage::string`.
+        let reference = unsafe { &*ptr::from_ref::<[u32]>(&value) };
+        self.numbers.push(value);
+        reference
+    }
+}
+
+/// A detected card type, with every field stored inline.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CctCardType {
+    /// The brand identifier, e.g. `"american-express"`, NUL terminated.
+    pub brand: [c_char; CCT_BRAND_LEN],
+    /// The display name, e.g. `"American Express"`, NUL terminated.
+    pub nice_type: [c_char; CCT_NICE_TYPE_LEN],
+    /// The name of the security code, e.g. `"CID"`, NUL terminated.
+    pub code_name: [c_char; CCT_CODE_NAME_LEN],
+    /// The number of digits of the security code.
+    pub code_size: u32,
+    /// How specific the matched pattern is.
+    pub match_strength: u32,
+    /// Positions of the spaces in a formatted number, the first `gaps_len` entries are set.
+    pub gaps: [u32; CCT_MAX_GAPS],
+    pub gaps_len: usize,
+    /// Allowed lengths of a full number, the first `lengths_len` entries are set.
+    pub lengths: [u32; CCT_MAX_LENGTHS],
+    pub lengths_len: usize,
+}
+
+/// A pattern of a card type to insert: a prefix if `max` is null, otherwise an inclusive range of prefixes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CctPattern {
+    pub min: *const c_char,
+    pub max: *const c_char,
+}
+
+/// A card type to insert with `cct_pool_insert_card_type`, the data is copied into the pool.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CctCardTypeSpec {
+    pub brand: *const c_char,
+    pub nice_type: *const c_char,
+    pub patterns: *const CctPattern,
+    pub patterns_len: usize,
+    pub gaps: *const u32,
+    pub gaps_len: usize,
+    pub lengths: *const u32,
+    pub lengths_len: usize,
+    pub code_name: *const c_char,
+    pub code_size: u32,
+}
+
+/// A list of detected card types allocated by `cct_pool_detect_alloc`, freed with `cct_card_type_list_free`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CctCardTypeList {
+    pub items: *mut CctCardType,
+    pub len: usize,
+}
+
+/// Creates a pool with the default card types.
+#[no_mangle]
+pub extern "C" fn cct_pool_new() -> *mut CctPool {
+    Box::into_raw(Box::new(CctPool {
+        pool: CreditCardPool::new(),
+        storage: Vec::new(),
+    }))
+}
+
+/// Creates a pool without card types.
+#[no_mangle]
+pub extern "C" fn cct_pool_new_empty() -> *mut CctPool {
+    Box::into_raw(Box::new(CctPool {
+        pool: CreditCardPool::new_empty(),
+        storage: Vec::new(),
+    }))
+}
+
+/// Frees a pool, null is ignored.
+#[no_mangle]
+pub unsafe extern "C" fn cct_pool_free(pool: *mut CctPool) {
+    if !pool.is_null() {
+        drop(Box::from_raw(pool));
+    }
+}
+
+/// Inserts a card type, replacing the card type with the same brand. The data of `spec` is copied, and the copy of a
+/// replaced card type is freed.
+#[no_mangle]
+pub unsafe extern "C" fn cct_pool_insert_card_type(
+    pool: *mut CctPool,
+    spec: *const CctCardTypeSpec,
+) -> i32 {
+    let (Some(pool), Some(spec)) = (pool.as_mut(), spec.as_ref()) else {
+        return CCT_ERROR_NULL_POINTER;
+    };
+
+    let mut storage = Storage::default();
+    match card_type_from_spec(&mut storage, spec) {
+        Ok(card_type) => {
+            let brand = card_type.type_;
+            pool.pool.insert_card_type(card_type);
+            // The replaced card type is no longer in the pool.
+            pool.storage
+                .retain(|(stored_brand, _)| stored_brand.as_str() != brand.as_str());
+            pool.storage.push((brand, storage));
+            CCT_OK
+        }
+        Err(status) => status,
+    }
+}
+
+/// Removes the card type of a brand, unknown brands are ignored.
+#[no_mangle]
+pub unsafe extern "C" fn cct_pool_remove_card_type(
+    pool: *mut CctPool,
+    brand: *const c_char,
+) -> i32 {
+    let Some(pool) = pool.as_mut() else {
+        return CCT_ERROR_NULL_POINTER;
+    };
+    let brand = match to_str(brand) {
+        Ok(brand) => brand,
+        Err(status) => return status,
+    };
+
+    if let Some(card_type) = pool
+        .pool
+        .card_types()
+        .iter()
+        .find(|card_type| card_type.type_ == brand)
+    {
+        pool.pool.remove_card_type(card_type.type_);
+        // Compared with `brand`, the brand of the card type may point into the freed storage.
+        pool.storage
+            .retain(|(stored_brand, _)| stored_brand.as_str() != brand);
+    }
+
+    CCT_OK
+}
+
+/// Detects the card types of a full or partial card number.
+///
+/// Writes up to `out_len` matches to `out` and the total number of matches to `out_count`. Returns
+/// `CCT_ERROR_BUFFER_TOO_SMALL` if there are more than `out_len` matches, `out` can be null if `out_len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn cct_pool_detect(
+    pool: *const CctPool,
+    card_number: *const c_char,
+    out: *mut CctCardType,
+    out_len: usize,
+    out_count: *mut usize,
+) -> i32 {
+    let Some(out_count) = out_count.as_mut() else {
+        return CCT_ERROR_NULL_POINTER;
+    };
+    *out_count = 0;
+    if out.is_null() && out_len > 0 {
+        return CCT_ERROR_NULL_POINTER;
+    }
+
+    let card_types = match detect(pool, card_number) {
+        Ok(card_types) => card_types,
+        Err(status) => return status,
+    };
+    *out_count = card_types.len();
+
+    if out_len > 0 {
+        let out = slice::from_raw_parts_mut(out, out_len);
+        for (slot, card_type) in out.iter_mut().zip(&card_types) {
+            *slot = *card_type;
+        }
+    }
+
+    if card_types.len() > out_len {
+        CCT_ERROR_BUFFER_TOO_SMALL
+    } else {
+        CCT_OK
+    }
+}
+
+/// Detects the card types of a full or partial card number into a list allocated by the library.
+///
+/// On success `out` must be freed with `cct_card_type_list_free`.
+#[no_mangle]
+pub unsafe extern "C" fn cct_pool_detect_alloc(
+    pool: *const CctPool,
+    card_number: *const c_char,
+    out: *mut CctCardTypeList,
+) -> i32 {
+    let Some(out) = out.as_mut() else {
+        return CCT_ERROR_NULL_POINTER;
+    };
+    *out = CctCardTypeList {
+        items: ptr::null_mut(),
+        len: 0,
+    };
+
+    match detect(pool, card_number) {
+        Ok(card_types) => {
+            let card_types = card_types.into_boxed_slice();
+            out.len = card_types.len();
+            out.items = Box::into_raw(card_types).cast::<CctCardType>();
+            CCT_OK
+        }
+        Err(status) => status,
+    }
+}
+
+/// Frees a list returned by `cct_pool_detect_alloc`, the list is reset to empty.
+#[no_mangle]
+pub unsafe extern "C" fn cct_card_type_list_free(list: *mut CctCardTypeList) {
+    let Some(list) = list.as_mut() else {
+        return;
+    };
+
+    if !list.items.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+            list.items, list.len,
+        )));
+    }
+    list.items = ptr::null_mut();
+    list.len = 0;
+}
+
+/// Returns a static, NUL terminated description of a status code.
+#[no_mangle]
+pub extern "C" fn cct_status_message(status: i32) -> *const c_char {
+    let message: &'static CStr = match status {
+        CCT_OK => c"ok",
+        CCT_ERROR_NULL_POINTER => c"null pointer",
+        CCT_ERROR_INVALID_UTF8 => c"invalid UTF-8",
+        CCT_ERROR_INVALID_CARD_NUMBER => c"invalid card number",
+        CCT_ERROR_BUFFER_TOO_SMALL => c"buffer too small",
+        CCT_ERROR_INVALID_CARD_TYPE => c"invalid card type",
+        _ => c"unknown status",
+    };
+    message.as_ptr()
+}
+
+unsafe fn detect(
+    pool: *const CctPool,
+    card_number: *const c_char,
+) -> Result<Vec<CctCardType>, i32> {
+    let pool = pool.as_ref().ok_or(CCT_ERROR_NULL_POINTER)?;
+    let card_number = to_str(card_number)?;
+    if !card_number.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(CCT_ERROR_INVALID_CARD_NUMBER);
+    }
+
+    let card_types = pool
+        .pool
+        .get_credit_card_type(card_number)
+        .map_err(|_| CCT_ERROR_INVALID_CARD_NUMBER)?;
+
+    card_types.iter().map(to_cct_card_type).collect()
+}
+
+fn to_cct_card_type(card_type: &CreditCardType) -> Result<CctCardType, i32> {
+    let mut cct_card_type = CctCardType {
+        brand: [0; CCT_BRAND_LEN],
+        nice_type: [0; CCT_NICE_TYPE_LEN],
+        code_name: [0; CCT_CODE_NAME_LEN],
+        code_size: card_type.code.size,
+        match_strength: card_type.match_strength,
+        gaps: [0; CCT_MAX_GAPS],
+        gaps_len: card_type.gaps.len(),
+        lengths: [0; CCT_MAX_LENGTHS],
+        lengths_len: card_type.lengths.len(),
+    };
+
+    copy_str(&mut cct_card_type.brand, card_type.type_.as_str())?;
+    copy_str(&mut cct_card_type.nice_type, card_type.nice_type)?;
+    copy_str(&mut cct_card_type.code_name, card_type.code.name)?;
+    copy_numbers(&mut cct_card_type.gaps, card_type.gaps)?;
+    copy_numbers(&mut cct_card_type.lengths, card_type.lengths)?;
+
+    Ok(cct_card_type)
+}
+
+unsafe fn card_type_from_spec(
+    storage: &mut Storage,
+    spec: &CctCardTypeSpec,
+) -> Result<CreditCardType, i32> {
+    let brand = to_str(spec.brand)?;
+    let nice_type = to_str(spec.nice_type)?;
+    let code_name = to_str(spec.code_name)?;
+    let patterns = to_slice(spec.patterns, spec.patterns_len)?;
+    let gaps = to_slice(spec.gaps, spec.gaps_len)?;
+    let lengths = to_slice(spec.lengths, spec.lengths_len)?;
+
+    if brand.is_empty()
+        || brand.len() >= CCT_BRAND_LEN
+        || nice_type.len() >= CCT_NICE_TYPE_LEN
+        || code_name.len() >= CCT_CODE_NAME_LEN
+        || patterns.is_empty()
+        || gaps.len() > CCT_MAX_GAPS
+        || lengths.is_empty()
+        || lengths.len() > CCT_MAX_LENGTHS
+    {
+        return Err(CCT_ERROR_INVALID_CARD_TYPE);
+    }
+
+    let mut pattern_list = Vec::with_capacity(patterns.len());
+    for pattern in patterns {
+        let min = to_str(pattern.min)?;
+        let is_digits =
+            |value: &str| !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit());
+        let pattern = if pattern.max.is_null() {
+            if !is_digits(min) {
+                return Err(CCT_ERROR_INVALID_CARD_TYPE);
+            }
+            alloc::vec![storage.string(min.to_string())]
+        } else {
+            let max = to_str(pattern.max)?;
+            if !is_digits(min) || !is_digits(max) || min.len() != max.len() || min > max {
+                return Err(CCT_ERROR_INVALID_CARD_TYPE);
+            }
+            alloc::vec![
+                storage.string(min.to_string()),
+                storage.string(max.to_string())
+            ]
+        };
+        pattern_list.push(storage.pattern(pattern));
+    }
+
+    let type_ = match brand.parse::<CardBrand>() {
+        Ok(brand) => brand,
+        Err(_) => CardBrand::Custom(storage.string(brand.to_string())),
+    };
+
+    Ok(CreditCardType {
+        nice_type: storage.string(nice_type.to_string()),
+        type_,
+        patterns: storage.pattern_list(pattern_list),
+        validity: &[],
+        ranges: &[],
+        gaps: storage.numbers(gaps),
+        lengths: storage.numbers(lengths),
+        code: Code {
+            name: storage.string(code_name.to_string()),
+            size: spec.code_size,
+        },
+        match_strength: 0,
+    })
+}
+
+unsafe fn to_str<'a>(value: *const c_char) -> Result<&'a str, i32> {
+    if value.is_null() {
+        return Err(CCT_ERROR_NULL_POINTER);
+    }
+
+    CStr::from_ptr(value)
+        .to_str()
+        .map_err(|_| CCT_ERROR_INVALID_UTF8)
+}
+
+unsafe fn to_slice<'a, T>(values: *const T, len: usize) -> Result<&'a [T], i32> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if values.is_null() {
+        return Err(CCT_ERROR_NULL_POINTER);
+    }
+
+    Ok(slice::from_raw_parts(values, len))
+}
+
+fn copy_str(out: &mut [c_char], value: &str) -> Result<(), i32> {
+    // The last byte is kept for the terminating NUL.
+    if value.len() >= out.len() || value.bytes().any(|byte| byte == 0) {
+        return Err(CCT_ERROR_INVALID_CARD_TYPE);
+    }
+
+    for (slot, byte) in out.iter_mut().zip(value.bytes()) {
+        *slot = byte.cast_signed();
+    }
+
+    Ok(())
+}
+
+fn copy_numbers(out: &mut [u32], values: &[u32]) -> Result<(), i32> {
+    out.get_mut(..values.len())
+        .ok_or(CCT_ERROR_INVALID_CARD_TYPE)?
+        .copy_from_slice(values);
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use alloc::string::String;
+
+use crate::card_types::{Code, CreditCardType};
+
+/// The character standing for a digit in input masks.
+const MASK_DIGIT: char = '#';
+
+impl CreditCardType {
+    /// Formats a card number by inserting a space at each of the card type's [`gaps`](CreditCardType::gaps).
+    ///
+    /// Spaces and dashes already present in the number are removed first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::CreditCardPool;
+    ///
+    /// let pool = CreditCardPool::new();
+    /// let card_type = &pool.get_credit_card_type("378282246310005").unwrap()[0];
+    ///
+    /// assert_eq!(card_type.format_card_number("378282246310005"), "3782 822463 10005");
+    /// ```
+    #[must_use]
+    pub fn format_card_number(&self, card_number: impl AsRef<str>) -> String {
+        let mut formatted = String::new();
+
+        for (index, character) in card_number
+            .as_ref()
+            .chars()
+            .filter(|character| !matches!(character, ' ' | '-'))
+            .enumerate()
+        {
+            if index > 0 && self.gaps.iter().any(|gap| *gap as usize == index) {
+                formatted.push(' ');
+            }
+            formatted.push(character);
+        }
+
+        formatted
+    }
+
+    /// Returns the largest allowed length of a full number, `0` if the card type has no lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::CreditCardPool;
+    ///
+    /// let pool = CreditCardPool::new();
+    /// let card_type = &pool.get_credit_card_type("4111111111111111").unwrap()[0];
+    ///
+    /// assert_eq!(card_type.max_length(), 19);
+    /// ```
+    #[must_use]
+    pub fn max_length(&self) -> u32 {
+        self.lengths.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Returns an input mask for a number of [`max_length`](CreditCardType::max_length) digits, with a `#` for each
+    /// digit and a space at each of the [`gaps`](CreditCardType::gaps).
+    ///
+    /// The mask has the layout of [`format_card_number`](CreditCardType::format_card_number).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::CreditCardPool;
+    ///
+    /// let pool = CreditCardPool::new();
+    /// let card_type = &pool.get_credit_card_type("378282246310005").unwrap()[0];
+    ///
+    /// assert_eq!(card_type.input_mask(), "#### ###### #####");
+    /// assert_eq!(card_type.max_input_length(), 17);
+    /// ```
+    #[must_use]
+    pub fn input_mask(&self) -> String {
+        let digits = (0..self.max_length())
+            .map(|_| MASK_DIGIT)
+            .collect::<String>();
+        self.format_card_number(digits)
+    }
+
+    /// Returns the length of a formatted number of [`max_length`](CreditCardType::max_length) digits, spaces
+    /// included, for the `maxlength` attribute of a text input.
+    #[must_use]
+    pub fn max_input_length(&self) -> usize {
+        self.input_mask().len()
+    }
+}
+
+impl Code {
+    /// Returns an input mask for the security code, with a `#` for each digit. Empty if the card has no code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::CreditCardPool;
+    ///
+    /// let pool = CreditCardPool::new();
+    /// let card_type = &pool.get_credit_card_type("378282246310005").unwrap()[0];
+    ///
+    /// assert_eq!(card_type.code.mask(), "####");
+    /// ```
+    #[must_use]
+    pub fn mask(&self) -> String {
+        (0..self.size).map(|_| MASK_DIGIT).collect()
+    }
+}
+
+/// Masks a card number, keeping only the last four digits visible.
+///
+/// Characters other than digits, like spaces and dashes, are kept as they are.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::mask_card_number;
+///
+/// assert_eq!(mask_card_number("4111 1111 1111 1111"), "**** **** **** 1111");
+/// ```
+#[must_use]
+pub fn mask_card_number(card_number: impl AsRef<str>) -> String {
+    let card_number = card_number.as_ref();
+    let digits = card_number.chars().filter(char::is_ascii_digit).count();
+    let mut remaining_digits = digits;
+
+    card_number
+        .chars()
+        .map(|character| {
+            if !character.is_ascii_digit() {
+                return character;
+            }
+            remaining_digits -= 1;
+            if remaining_digits < 4 {
+                character
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
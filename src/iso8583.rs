@@ -0,0 +1,151 @@
+//! Decoders for the card data fields of ISO 8583 messages.
+//!
+//! Field 2 is the card number and field 35 the Track 2 data, both are LLVAR fields: a two digit length followed by
+//! the data. The decoders return the rest of the message after the field, so they can be called while walking through
+//! the fields of a message.
+//!
+//! ```
+//! use credit_card_types::{iso8583::{self, Encoding}, CardBrand, CreditCardPool};
+//!
+//! let field = b"164111111111111111";
+//! let (pan, rest) = iso8583::decode_pan(field, Encoding::Ascii).unwrap();
+//! assert!(rest.is_empty());
+//!
+//! let pool = CreditCardPool::new();
+//! assert_eq!(pool.get_credit_card_type(&pan).unwrap()[0].type_, CardBrand::Visa);
+//! ```
+
+use alloc::string::String;
+
+use crate::{
+    errors::CardTypeError,
+    pan::{zeroize_string, Pan},
+    track::Track2,
+};
+
+/// The longest card number of field 2.
+const MAX_PAN_LENGTH: usize = 19;
+/// The longest Track 2 data of field 35.
+const MAX_TRACK2_LENGTH: usize = 37;
+
+/// The encoding of a field, its length prefix included.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Encoding {
+    /// ASCII characters, the length is two ASCII digits.
+    Ascii,
+    /// Packed BCD, the length is one BCD byte. An odd number of digits is padded at the end with `0` or `F`, and
+    /// the Track 2 separator is the nibble `D`.
+    Bcd,
+    /// Packed BCD padded at the start with `0`, the other way around from [`Encoding::Bcd`].
+    BcdRightJustified,
+}
+
+/// Decodes field 2, the card number, at the start of `data`.
+///
+/// Returns the card number, and the rest of `data` after the field.
+///
+/// # Errors
+///
+/// Returns [`CardTypeError::InvalidIso8583Field`] if the field is truncated, longer than 19 digits or malformed, and
+/// [`CardTypeError::InvalidCardNumber`] if the card number is not made of digits.
+pub fn decode_pan(data: &[u8], encoding: Encoding) -> Result<(Pan, &[u8]), CardTypeError> {
+    let (pan, rest) = decode_llvar(data, encoding, MAX_PAN_LENGTH)?;
+    if !pan.bytes().all(|byte| byte.is_ascii_digit()) {
+        zeroize_string(pan);
+        return Err(CardTypeError::InvalidCardNumber);
+    }
+
+    Ok((Pan::new(pan)?, rest))
+}
+
+/// Decodes field 35, the Track 2 data, at the start of `data`.
+///
+/// Returns the Track 2 data, and the rest of `data` after the field.
+///
+/// # Errors
+///
+/// Returns [`CardTypeError::InvalidIso8583Field`] if the field is truncated, longer than 37 characters or
+/// malformed, and the errors of [`Track2::parse`].
+pub fn decode_track2(data: &[u8], encoding: Encoding) -> Result<(Track2, &[u8]), CardTypeError> {
+    let (data, rest) = decode_llvar(data, encoding, MAX_TRACK2_LENGTH)?;
+    let track2 = Track2::parse(&data);
+    zeroize_string(data);
+
+    Ok((track2?, rest))
+}
+
+/// Decodes an LLVAR field of up to `max_length` characters.
+fn decode_llvar(
+    data: &[u8],
+    encoding: Encoding,
+    max_length: usize,
+) -> Result<(String, &[u8]), CardTypeError> {
+    let truncated = CardTypeError::InvalidIso8583Field("truncated field");
+
+    let (length, data) = match encoding {
+        Encoding::Ascii => {
+            let length = data.get(..2).ok_or(truncated.clone())?;
+            let length = core::str::from_utf8(length)
+                .ok()
+                .filter(|length| length.bytes().all(|byte| byte.is_ascii_digit()))
+                .and_then(|length| length.parse::<usize>().ok())
+                .ok_or(CardTypeError::InvalidIso8583Field("invalid length"))?;
+            (length, &data[2..])
+        }
+        Encoding::Bcd | Encoding::BcdRightJustified => {
+            let (&length, data) = data.split_first().ok_or(truncated.clone())?;
+            let [tens, units] = [length >> 4, length & 0x0F];
+            if tens > 9 || units > 9 {
+                return Err(CardTypeError::InvalidIso8583Field("invalid length"));
+            }
+            (usize::from(tens * 10 + units), data)
+        }
+    };
+    if length > max_length {
+        return Err(CardTypeError::InvalidIso8583Field(
+            "length over the maximum",
+        ));
+    }
+
+    match encoding {
+        Encoding::Ascii => {
+            let value = data.get(..length).ok_or(truncated)?;
+            let value = core::str::from_utf8(value)
+                .map_err(|_| CardTypeError::InvalidIso8583Field("invalid character"))?;
+            Ok((String::from(value), &data[length..]))
+        }
+        Encoding::Bcd | Encoding::BcdRightJustified => {
+            let byte_length = length.div_ceil(2);
+            let value = data.get(..byte_length).ok_or(truncated)?;
+            let padding = byte_length * 2 - length;
+            let mut nibbles = value.iter().flat_map(|byte| [byte >> 4, byte & 0x0F]);
+
+            let padding_nibble = if padding == 0 {
+                None
+            } else if encoding == Encoding::Bcd {
+                nibbles.next_back()
+            } else {
+                nibbles.next()
+            };
+            match (encoding, padding_nibble) {
+                (_, None | Some(0x00)) | (Encoding::Bcd, Some(0x0F)) => {}
+                _ => return Err(CardTypeError::InvalidIso8583Field("invalid padding")),
+            }
+
+            let mut value = String::with_capacity(length);
+            for nibble in nibbles {
+                match nibble {
+                    0..=9 => value.push(char::from(b'0' + nibble)),
+                    0x0D => value.push('='),
+                    _ => {
+                        // The digits may be part of a card number.
+                        zeroize_string(value);
+                        return Err(CardTypeError::InvalidIso8583Field("invalid character"));
+                    }
+                }
+            }
+            Ok((value, &data[byte_length..]))
+        }
+    }
+}
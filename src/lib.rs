@@ -1,5 +1,82 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+mod acceptance;
+mod aid;
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "alloc")]
+mod builder;
+mod card_brand;
+mod card_range;
 mod card_types;
+#[cfg(feature = "alloc")]
+mod detector;
+#[cfg(feature = "alloc")]
+mod diff;
+mod effective;
+#[cfg(feature = "alloc")]
+mod emv;
 mod errors;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "alloc")]
+mod formatting;
+#[cfg(feature = "alloc")]
+pub mod iso8583;
+#[cfg(feature = "alloc")]
+mod pan;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod regions;
+#[cfg(feature = "std")]
+mod scan;
+mod service_code;
+mod static_pool;
+#[cfg(feature = "alloc")]
+mod track;
 mod utils;
+mod validation;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use crate::card_types::{Code, CreditCardPool, CreditCardType};
+#[cfg(feature = "alloc")]
+pub use crate::acceptance::{Acceptance, AcceptancePolicy, Rejection};
+#[cfg(feature = "alloc")]
+pub use crate::aid::AidRegistry;
+pub use crate::aid::{AidEntry, DEFAULT_AIDS};
+#[cfg(feature = "std")]
+pub use crate::batch::{BatchFormat, BatchProcessor, BatchSummary};
+#[cfg(feature = "alloc")]
+pub use crate::builder::{CreditCardPoolBuilder, Preset};
+pub use crate::card_brand::CardBrand;
+pub use crate::card_range::{CardMatch, CardRange, FundingType, RangeKind, StaticCardMatches};
+#[cfg(feature = "alloc")]
+pub use crate::card_types::CreditCardPool;
+pub use crate::card_types::{Code, CreditCardType, DEFAULT_CARD_TYPES};
+#[cfg(feature = "alloc")]
+pub use crate::detector::IncrementalDetector;
+#[cfg(feature = "alloc")]
+pub use crate::diff::{CardTypeChange, Change, DataVersion, PoolDiff, Reclassification};
+pub use crate::effective::{Date, PatternValidity};
+#[cfg(feature = "alloc")]
+pub use crate::emv::{BrandCheck, EmvData, Tlv, TlvIter};
+#[cfg(feature = "std")]
+pub use crate::errors::BatchError;
+pub use crate::errors::CardTypeError;
+#[cfg(feature = "alloc")]
+pub use crate::formatting::mask_card_number;
+#[cfg(feature = "alloc")]
+pub use crate::pan::Pan;
+#[cfg(feature = "std")]
+pub use crate::scan::{PanMatch, PanScanner};
+pub use crate::service_code::{Authorization, Interchange, PinRequirement, ServiceCode, Services};
+pub use crate::static_pool::{StaticCardPool, StaticMatches};
+#[cfg(feature = "alloc")]
+pub use crate::track::{Expiry, Track1, Track2};
+pub use crate::validation::{luhn_check, luhn_check_digit, Validation};
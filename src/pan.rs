@@ -0,0 +1,151 @@
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt,
+    str::FromStr,
+    sync::atomic::{compiler_fence, Ordering},
+};
+
+use crate::{errors::CardTypeError, formatting::mask_card_number};
+
+/// The longest card number a [`Pan`] can hold.
+const MAX_DIGITS: usize = 19;
+
+/// A card number (Primary Account Number) that is wiped from memory when dropped.
+///
+/// The number is validated on construction: spaces and dashes are removed, and what is left must be 1 to 19 ASCII
+/// digits. `Debug` and `Display` only show the last four digits, so a `Pan` can not leak through logs by accident.
+///
+/// A `Pan` can be passed to everything that takes a card number, like [`CreditCardPool::get_credit_card_type`],
+/// [`CreditCardType::format_card_number`] and [`mask_card_number`]. Use [`Pan::expose`] when the digits are needed.
+///
+/// [`CreditCardPool::get_credit_card_type`]: crate::CreditCardPool::get_credit_card_type
+/// [`CreditCardType::format_card_number`]: crate::CreditCardType::format_card_number
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{CardBrand, CreditCardPool, Pan};
+///
+/// let pan: Pan = "4111 1111 1111 1111".parse().unwrap();
+///
+/// assert_eq!(format!("{pan:?}"), "Pan(************1111)");
+///
+/// let pool = CreditCardPool::new();
+/// let card_types = pool.get_credit_card_type(&pan).unwrap();
+///
+/// assert_eq!(card_types[0].type_, CardBrand::Visa);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Pan(Vec<u8>);
+
+impl Pan {
+    /// Creates a `Pan`, reusing the buffer of `card_number`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::InvalidCardNumber`] if the number is empty, longer than 19 digits, or contains anything
+    /// other than digits, spaces and dashes. The buffer is wiped in that case too.
+    pub fn new(card_number: impl Into<String>) -> Result<Pan, CardTypeError> {
+        let mut digits = card_number.into().into_bytes();
+        digits.retain(|byte| !matches!(byte, b' ' | b'-'));
+
+        if digits.is_empty() || digits.len() > MAX_DIGITS || !digits.iter().all(u8::is_ascii_digit)
+        {
+            zeroize(&mut digits);
+            return Err(CardTypeError::InvalidCardNumber);
+        }
+
+        Ok(Pan(digits))
+    }
+
+    /// Returns the digits of the card number.
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        core::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// Returns the number of digits.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Always returns `false`, a `Pan` has at least one digit.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the last four digits, or all of them if the number is shorter.
+    #[must_use]
+    pub fn last_four(&self) -> &str {
+        let expose = self.expose();
+        &expose[expose.len().saturating_sub(4)..]
+    }
+}
+
+impl Drop for Pan {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+impl AsRef<str> for Pan {
+    fn as_ref(&self) -> &str {
+        self.expose()
+    }
+}
+
+impl FromStr for Pan {
+    type Err = CardTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = String::with_capacity(s.len());
+        digits.extend(
+            s.chars()
+                .filter(|character| !matches!(character, ' ' | '-')),
+        );
+        Pan::new(digits)
+    }
+}
+
+impl TryFrom<String> for Pan {
+    type Error = CardTypeError;
+
+    fn try_from(card_number: String) -> Result<Self, Self::Error> {
+        Pan::new(card_number)
+    }
+}
+
+impl fmt::Debug for Pan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Pan({self})")
+    }
+}
+
+impl fmt::Display for Pan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&mask_card_number(self.expose()))
+    }
+}
+
+/// Wipes `string`, a copy of a card number that is not kept in a [`Pan`], like the Track 2 data it was read from.
+pub(crate) fn zeroize_string(string: String) {
+    zeroize(&mut string.into_bytes());
+}
+
+/// Overwrites the whole allocation of `bytes` with zeros, including the spare capacity, which may still hold digits
+/// moved by [`Vec::retain`].
+fn zeroize(bytes: &mut Vec<u8>) {
+    let capacity = bytes.capacity();
+    let pointer = bytes.as_mut_ptr();
+
+    for offset in 0..capacity {
+        // SAFETY: `offset` is within the allocation of `bytes`, and any value is a valid `u8`. Volatile writes are not
+        // removed by the compiler even though the buffer is about to be freed.
+        unsafe { core::ptr::write_volatile(pointer.add(offset), 0) };
+    }
+    bytes.clear();
+
+    compiler_fence(Ordering::SeqCst);
+}
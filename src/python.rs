@@ -0,0 +1,338 @@
+//! Python bindings, enabled by the `python` feature.
+//!
+//! The extension module is built with [maturin](https://www.maturin.rs), see `pyproject.toml`:
+//!
+//! ```python
+//! from credit_card_types import CreditCardPool
+//!
+//! pool = CreditCardPool()
+//!
+//! [card_type] = pool.get_credit_card_type("4111111111111111")
+//! print(card_type.type, card_type.nice_type, card_type.gaps, card_type.code.size)
+//!
+//! df["brand"] = pool.detect_brands(df["card_number"].tolist())
+//! ```
+//!
+//! Card numbers may contain spaces and dashes. Invalid input raises `ValueError`, except in the batch methods, which
+//! return `None` for invalid numbers so a bad row does not fail a whole column. The batch methods release the GIL.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{
+    card_types::{Code, CreditCardPool, CreditCardType},
+    errors::CardTypeError,
+    formatting::mask_card_number,
+    validation::{luhn_check, Validation},
+};
+
+/// A pool of card types, `CreditCardPool` in Python.
+#[pyclass(name = "CreditCardPool", module = "credit_card_types", frozen)]
+#[derive(Debug, Clone, Default)]
+pub struct PyCreditCardPool(CreditCardPool);
+
+#[pymethods]
+impl PyCreditCardPool {
+    /// Creates a pool with the default card types.
+    #[new]
+    #[must_use]
+    pub fn new() -> PyCreditCardPool {
+        PyCreditCardPool(CreditCardPool::new())
+    }
+
+    /// Returns the card types matching a full or partial card number.
+    ///
+    /// # Errors
+    ///
+    /// Raises `ValueError` if the card number contains anything other than digits, spaces and dashes.
+    pub fn get_credit_card_type(&self, card_number: &str) -> PyResult<Vec<PyCardType>> {
+        Ok(self
+            .detect(card_number)
+            .map_err(to_py_err)?
+            .into_iter()
+            .map(PyCardType)
+            .collect())
+    }
+
+    /// Returns the card types of every number, `None` for invalid numbers. Releases the GIL.
+    #[must_use]
+    pub fn detect_many(
+        &self,
+        py: Python<'_>,
+        card_numbers: Vec<String>,
+    ) -> Vec<Option<Vec<PyCardType>>> {
+        py.allow_threads(|| {
+            card_numbers
+                .into_iter()
+                .map(|card_number| {
+                    let card_types = self.detect(&card_number).ok()?;
+                    Some(card_types.into_iter().map(PyCardType).collect())
+                })
+                .collect()
+        })
+    }
+
+    /// Returns the brand of every number, e.g. `"visa"`, or `None` if the number is invalid, unknown or ambiguous.
+    /// Releases the GIL.
+    #[must_use]
+    pub fn detect_brands(&self, py: Python<'_>, card_numbers: Vec<String>) -> Vec<Option<String>> {
+        py.allow_threads(|| {
+            card_numbers
+                .into_iter()
+                .map(
+                    |card_number| match self.detect(&card_number).ok()?.as_slice() {
+                        [card_type] => Some(card_type.type_.to_string()),
+                        _ => None,
+                    },
+                )
+                .collect()
+        })
+    }
+
+    /// Validates a full card number.
+    ///
+    /// # Errors
+    ///
+    /// Raises `ValueError` if the card number contains anything other than digits, spaces and dashes.
+    pub fn validate_card_number(&self, card_number: &str) -> PyResult<PyValidation> {
+        self.0
+            .validate_card_number(normalize(card_number).map_err(to_py_err)?)
+            .map(PyValidation)
+            .map_err(to_py_err)
+    }
+
+    /// Returns whether every number is valid, invalid input is `False`. Releases the GIL.
+    #[must_use]
+    pub fn validate_many(&self, py: Python<'_>, card_numbers: Vec<String>) -> Vec<bool> {
+        py.allow_threads(|| {
+            card_numbers
+                .into_iter()
+                .map(|card_number| {
+                    normalize(&card_number)
+                        .and_then(|card_number| self.0.validate_card_number(card_number))
+                        .is_ok_and(|validation| validation.is_valid())
+                })
+                .collect()
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CreditCardPool({} card types)", self.0.card_types().len())
+    }
+}
+
+impl PyCreditCardPool {
+    fn detect(&self, card_number: &str) -> Result<Vec<CreditCardType>, CardTypeError> {
+        self.0.get_credit_card_type(normalize(card_number)?)
+    }
+}
+
+/// A card type returned by detection, `CardType` in Python.
+#[pyclass(name = "CardType", module = "credit_card_types", frozen)]
+#[derive(Debug, Clone)]
+pub struct PyCardType(CreditCardType);
+
+#[pymethods]
+impl PyCardType {
+    /// The brand identifier, e.g. `"american-express"`.
+    #[getter(r#type)]
+    #[must_use]
+    pub fn type_(&self) -> &'static str {
+        self.0.type_.as_str()
+    }
+
+    /// The display name, e.g. `"American Express"`.
+    #[getter]
+    #[must_use]
+    pub fn nice_type(&self) -> &'static str {
+        self.0.nice_type
+    }
+
+    /// Positions of the spaces in a formatted number.
+    #[getter]
+    #[must_use]
+    pub fn gaps(&self) -> Vec<u32> {
+        self.0.gaps.to_vec()
+    }
+
+    /// Allowed lengths of a full number.
+    #[getter]
+    #[must_use]
+    pub fn lengths(&self) -> Vec<u32> {
+        self.0.lengths.to_vec()
+    }
+
+    /// The largest allowed length of a full number.
+    #[getter]
+    #[must_use]
+    pub fn max_length(&self) -> u32 {
+        self.0.max_length()
+    }
+
+    /// An input mask like `"#### ###### #####"`, with a `#` for each digit.
+    #[getter]
+    #[must_use]
+    pub fn input_mask(&self) -> String {
+        self.0.input_mask()
+    }
+
+    /// The length of a formatted number, spaces included.
+    #[getter]
+    #[must_use]
+    pub fn max_input_length(&self) -> usize {
+        self.0.max_input_length()
+    }
+
+    /// The security code on the back of the card.
+    #[getter]
+    #[must_use]
+    pub fn code(&self) -> PyCode {
+        PyCode(self.0.code.clone())
+    }
+
+    /// How specific the matched pattern is, longer patterns are stronger.
+    #[getter]
+    #[must_use]
+    pub fn match_strength(&self) -> u32 {
+        self.0.match_strength
+    }
+
+    /// Formats a card number with the gaps of the card type.
+    #[must_use]
+    pub fn format_card_number(&self, card_number: &str) -> String {
+        self.0.format_card_number(card_number)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CardType(type={:?})", self.0.type_.as_str())
+    }
+}
+
+/// The security code of a card type, `CardCode` in Python.
+#[pyclass(name = "CardCode", module = "credit_card_types", frozen)]
+#[derive(Debug, Clone)]
+pub struct PyCode(Code);
+
+#[pymethods]
+impl PyCode {
+    /// The name of the code, e.g. `"CVV"`.
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.0.name
+    }
+
+    /// The number of digits.
+    #[getter]
+    #[must_use]
+    pub fn size(&self) -> u32 {
+        self.0.size
+    }
+
+    /// An input mask like `"###"`, empty if the card has no code.
+    #[getter]
+    #[must_use]
+    pub fn mask(&self) -> String {
+        self.0.mask()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CardCode(name={:?}, size={})", self.0.name, self.0.size)
+    }
+}
+
+/// The result of validating a card number, `Validation` in Python.
+#[pyclass(name = "Validation", module = "credit_card_types", frozen)]
+#[derive(Debug, Clone)]
+pub struct PyValidation(Validation);
+
+#[pymethods]
+impl PyValidation {
+    /// Whether the number has a single card type, a valid length and a valid checksum.
+    #[getter]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    /// Whether the number passes the Luhn checksum.
+    #[getter]
+    #[must_use]
+    pub fn luhn_valid(&self) -> bool {
+        self.0.luhn_valid
+    }
+
+    /// Whether the length of the number is allowed by the card type.
+    #[getter]
+    #[must_use]
+    pub fn length_valid(&self) -> bool {
+        self.0.length_valid
+    }
+
+    /// The card type, `None` if no card type or more than one card type matches.
+    #[getter]
+    #[must_use]
+    pub fn card_type(&self) -> Option<PyCardType> {
+        self.0.card_type.clone().map(PyCardType)
+    }
+
+    fn __bool__(&self) -> bool {
+        self.0.is_valid()
+    }
+}
+
+/// Masks a card number, keeping only the last four digits visible.
+#[pyfunction(name = "mask_card_number")]
+#[must_use]
+pub fn py_mask_card_number(card_number: &str) -> String {
+    mask_card_number(card_number)
+}
+
+/// Checks a card number against the Luhn checksum, spaces and dashes are ignored.
+#[pyfunction(name = "luhn_check")]
+#[must_use]
+pub fn py_luhn_check(card_number: &str) -> bool {
+    let card_number = card_number
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '-'))
+        .collect::<String>();
+
+    luhn_check(&card_number)
+}
+
+/// The `credit_card_types` extension module.
+#[pymodule]
+fn credit_card_types(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyCreditCardPool>()?;
+    module.add_class::<PyCardType>()?;
+    module.add_class::<PyCode>()?;
+    module.add_class::<PyValidation>()?;
+    module.add_function(wrap_pyfunction!(py_mask_card_number, module)?)?;
+    module.add_function(wrap_pyfunction!(py_luhn_check, module)?)?;
+
+    Ok(())
+}
+
+/// Removes spaces and dashes, numbers are often stored formatted.
+fn normalize(card_number: &str) -> Result<String, CardTypeError> {
+    let card_number = card_number
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '-'))
+        .collect::<String>();
+
+    if card_number.bytes().all(|byte| byte.is_ascii_digit()) {
+        Ok(card_number)
+    } else {
+        Err(CardTypeError::InvalidCardNumber)
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn to_py_err(error: CardTypeError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
@@ -0,0 +1,192 @@
+//! Card types of regional networks, each pack is enabled by its own feature.
+//!
+//! | Feature | Pack | Card types |
+//! | --- | --- | --- |
+//! | `region-africa` | `AFRICA` | `VERVE` |
+//! | `region-eu` | `EU` | `BANCONTACT` |
+//! | `region-india` | `INDIA` | `RUPAY` |
+//! | `region-nordics` | `NORDICS` | `DANKORT` |
+//! | `region-turkey` | `TURKEY` | `TROY` |
+//! | `region-vietnam` | `VIETNAM` | `NAPAS` |
+//!
+//! `all-regions` enables every pack. The packs are not part of [`DEFAULT_CARD_TYPES`], add them to a pool with
+//! [`Extend`], [`ENABLED`] has the card types of every enabled pack:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use credit_card_types::{regions, CreditCardPool};
+//!
+//! let mut pool = CreditCardPool::new();
+//! pool.extend(regions::ENABLED.iter().cloned());
+//! # }
+//! ```
+//!
+//! Some markets have no pack on purpose, because their domestic cards have no IIN range of their own to detect:
+//!
+//! - Cartes Bancaires cards (France) are co-badged, they are issued in the Visa and Mastercard ranges. Declare the
+//!   ranges of your BIN table as co-badged with [`CardBrand::CartesBancaires`] instead, see
+//!   [`CardRange`](crate::CardRange).
+//! - Korean cards are issued by local card companies in the ranges of the international networks, mostly Visa,
+//!   Mastercard, JCB and American Express, which the default card types already detect. Their domestic-only ranges
+//!   belong to individual issuers and are not published as a network range.
+//!
+//! [`DEFAULT_CARD_TYPES`]: crate::DEFAULT_CARD_TYPES
+//! [`CardBrand::CartesBancaires`]: crate::CardBrand::CartesBancaires
+
+// Unused when no pack is enabled.
+#[allow(unused_imports)]
+use crate::{
+    card_range::{CardRange, FundingType},
+    card_types::{Code, CreditCardType},
+    CardBrand,
+};
+
+/// The card types of every enabled pack, sorted by brand.
+pub const ENABLED: &[CreditCardType] = &[
+    #[cfg(feature = "region-eu")]
+    BANCONTACT,
+    #[cfg(feature = "region-nordics")]
+    DANKORT,
+    #[cfg(feature = "region-vietnam")]
+    NAPAS,
+    #[cfg(feature = "region-india")]
+    RUPAY,
+    #[cfg(feature = "region-turkey")]
+    TROY,
+    #[cfg(feature = "region-africa")]
+    VERVE,
+];
+
+/// Verve, the domestic network of Nigeria.
+#[cfg(feature = "region-africa")]
+pub const VERVE: CreditCardType = CreditCardType {
+    nice_type: "Verve",
+    type_: CardBrand::Verve,
+    patterns: &[&["506099", "506198"], &["650002", "650027"]],
+    validity: &[],
+    ranges: &[],
+    gaps: &[4, 8, 12],
+    lengths: &[16, 18, 19],
+    code: Code {
+        name: "CVV",
+        size: 3,
+    },
+    match_strength: 0,
+};
+
+/// The card types of the `region-africa` pack.
+#[cfg(feature = "region-africa")]
+pub const AFRICA: &[CreditCardType] = &[VERVE];
+
+/// Bancontact, the domestic debit network of Belgium. The cards are co-badged with Maestro and have no security code.
+#[cfg(feature = "region-eu")]
+pub const BANCONTACT: CreditCardType = CreditCardType {
+    nice_type: "Bancontact",
+    type_: CardBrand::Bancontact,
+    patterns: &[&["6703"]],
+    validity: &[],
+    ranges: &[CardRange::new(&["6703"])
+        .with_co_badged(&[CardBrand::Maestro])
+        .with_funding(FundingType::Debit)],
+    gaps: &[4, 8, 12],
+    lengths: &[16, 17, 18, 19],
+    code: Code {
+        name: "CVC",
+        size: 0,
+    },
+    match_strength: 0,
+};
+
+/// The card types of the `region-eu` pack.
+#[cfg(feature = "region-eu")]
+pub const EU: &[CreditCardType] = &[BANCONTACT];
+
+/// The domestic network of India.
+#[cfg(feature = "region-india")]
+pub const RUPAY: CreditCardType = CreditCardType {
+    nice_type: "RuPay",
+    type_: CardBrand::RuPay,
+    patterns: &[
+        &["508500", "508999"],
+        &["606985", "607984"],
+        &["608001", "608500"],
+        &["652150", "653149"],
+        &["817200", "820299"],
+    ],
+    validity: &[],
+    ranges: &[],
+    gaps: &[4, 8, 12],
+    lengths: &[16],
+    code: Code {
+        name: "CVV",
+        size: 3,
+    },
+    match_strength: 0,
+};
+
+/// The card types of the `region-india` pack.
+#[cfg(feature = "region-india")]
+pub const INDIA: &[CreditCardType] = &[RUPAY];
+
+/// Dankort, the domestic debit network of Denmark. Visa/Dankort cards are detected as Visa, co-badged with Dankort.
+#[cfg(feature = "region-nordics")]
+pub const DANKORT: CreditCardType = CreditCardType {
+    nice_type: "Dankort",
+    type_: CardBrand::Dankort,
+    patterns: &[&["501900", "501999"]],
+    validity: &[],
+    ranges: &[CardRange::new(&["501900", "501999"]).with_funding(FundingType::Debit)],
+    gaps: &[4, 8, 12],
+    lengths: &[16],
+    code: Code {
+        name: "CVV",
+        size: 3,
+    },
+    match_strength: 0,
+};
+
+/// The card types of the `region-nordics` pack.
+#[cfg(feature = "region-nordics")]
+pub const NORDICS: &[CreditCardType] = &[DANKORT];
+
+/// Troy, the domestic network of Turkey.
+#[cfg(feature = "region-turkey")]
+pub const TROY: CreditCardType = CreditCardType {
+    nice_type: "Troy",
+    type_: CardBrand::Troy,
+    patterns: &[&["979200", "979289"]],
+    validity: &[],
+    ranges: &[],
+    gaps: &[4, 8, 12],
+    lengths: &[16],
+    code: Code {
+        name: "CVV",
+        size: 3,
+    },
+    match_strength: 0,
+};
+
+/// The card types of the `region-turkey` pack.
+#[cfg(feature = "region-turkey")]
+pub const TURKEY: &[CreditCardType] = &[TROY];
+
+/// NAPAS, the domestic network of Vietnam. Domestic cards have no security code.
+#[cfg(feature = "region-vietnam")]
+pub const NAPAS: CreditCardType = CreditCardType {
+    nice_type: "NAPAS",
+    type_: CardBrand::Napas,
+    patterns: &[&["9704"]],
+    validity: &[],
+    ranges: &[],
+    gaps: &[4, 8, 12],
+    lengths: &[16, 19],
+    code: Code {
+        name: "CVV",
+        size: 0,
+    },
+    match_strength: 0,
+};
+
+/// The card types of the `region-vietnam` pack.
+#[cfg(feature = "region-vietnam")]
+pub const VIETNAM: &[CreditCardType] = &[NAPAS];
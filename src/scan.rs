@@ -0,0 +1,278 @@
+use alloc::{string::String, vec::Vec};
+use std::io::{self, BufRead, Write};
+
+use crate::{card_types::CreditCardType, formatting::mask_card_number, CreditCardPool};
+
+/// The shortest and longest card numbers the scanner looks for.
+const MIN_DIGITS: usize = 12;
+const MAX_DIGITS: usize = 19;
+
+/// The most bytes of a line [`PanScanner::redact_stream`] holds in memory, longer lines are redacted in pieces.
+const MAX_LINE_LEN: usize = 64 * 1024;
+
+/// A card number found in text by [`PanScanner`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PanMatch {
+    /// Byte offset of the first digit.
+    pub start: usize,
+    /// Byte offset after the last digit.
+    pub end: usize,
+    /// The card type of the number.
+    pub card_type: CreditCardType,
+}
+
+/// Finds card numbers (PANs) in free text, like logs or support tickets.
+///
+/// A candidate is a run of 12 to 19 digits, optionally split in groups by single spaces or dashes, that is not part of
+/// a longer number or word. Candidates are only reported if they pass the Luhn checksum and are detected as exactly
+/// one card type of the pool with a valid length, which keeps order numbers, phone numbers and timestamps out.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{CreditCardPool, PanScanner};
+///
+/// let pool = CreditCardPool::new();
+/// let scanner = PanScanner::new(&pool);
+///
+/// let text = "payment failed for card 4111-1111-1111-1111, order 1234567890123";
+///
+/// assert_eq!(scanner.find_pans(text)[0].start, 24);
+/// assert_eq!(
+///     scanner.redact(text),
+///     "payment failed for card ****-****-****-1111, order 1234567890123"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct PanScanner<'a> {
+    pool: &'a CreditCardPool,
+}
+
+impl<'a> PanScanner<'a> {
+    /// Creates a scanner that confirms candidates with the card types of `pool`.
+    #[must_use]
+    pub fn new(pool: &'a CreditCardPool) -> Self {
+        PanScanner { pool }
+    }
+
+    /// Returns all card numbers in `text`, in order of appearance.
+    #[must_use]
+    pub fn find_pans(&self, text: &str) -> Vec<PanMatch> {
+        let bytes = text.as_bytes();
+        let mut matches = Vec::new();
+        let mut position = 0;
+
+        while position < bytes.len() {
+            if !bytes[position].is_ascii_digit()
+                || (position > 0 && bytes[position - 1].is_ascii_alphanumeric())
+            {
+                position += 1;
+                continue;
+            }
+
+            let groups = digit_groups(bytes, position);
+            let run_end = groups.last().map_or(position + 1, |group| group.1);
+            position = run_end;
+
+            if bytes.get(run_end).is_some_and(u8::is_ascii_alphanumeric) {
+                continue;
+            }
+
+            self.confirm_groups(text, &groups, &mut matches);
+        }
+
+        matches
+    }
+
+    /// Returns `text` with every card number masked, keeping only the last four digits visible.
+    #[must_use]
+    pub fn redact(&self, text: &str) -> String {
+        redact_matches(text, &self.find_pans(text))
+    }
+
+    /// Redacts a stream line by line, so inputs of any size can be processed with constant memory.
+    ///
+    /// Lines longer than 64 KiB are redacted in pieces, split after a character other than a letter, a digit, a space or
+    /// a dash, so no card number is cut in two unless a line has no such character for 64 KiB.
+    ///
+    /// Returns the number of card numbers that were redacted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading or writing fails, or if the input is not valid UTF-8.
+    pub fn redact_stream(
+        &self,
+        mut reader: impl BufRead,
+        mut writer: impl Write,
+    ) -> io::Result<usize> {
+        let mut buffer = Vec::new();
+        let mut redacted = 0;
+
+        loop {
+            let is_end = read_line(&mut reader, &mut buffer)?;
+            if buffer.is_empty() {
+                break;
+            }
+
+            let end = if is_end || buffer.ends_with(b"\n") {
+                buffer.len()
+            } else {
+                split_point(&buffer)
+            };
+            let text = core::str::from_utf8(&buffer[..end]).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                )
+            })?;
+
+            let pans = self.find_pans(text);
+            if pans.is_empty() {
+                writer.write_all(text.as_bytes())?;
+            } else {
+                redacted += pans.len();
+                writer.write_all(redact_matches(text, &pans).as_bytes())?;
+            }
+            buffer.drain(..end);
+        }
+
+        Ok(redacted)
+    }
+
+    /// Finds the card numbers in a run of digit groups.
+    ///
+    /// The whole run is tried first, then shorter runs starting and ending at group boundaries, so a card number next
+    /// to another number (`"ref 12 4111 1111 1111 1111"`) is still found.
+    fn confirm_groups(&self, text: &str, groups: &[(usize, usize)], matches: &mut Vec<PanMatch>) {
+        let mut first = 0;
+
+        'groups: while first < groups.len() {
+            let mut candidate_ends = Vec::new();
+            let mut digits = 0;
+            for (last, group) in groups.iter().enumerate().skip(first) {
+                digits += group.1 - group.0;
+                if digits > MAX_DIGITS {
+                    break;
+                }
+                if digits >= MIN_DIGITS {
+                    candidate_ends.push(last);
+                }
+            }
+
+            for last in candidate_ends.into_iter().rev() {
+                let (start, end) = (groups[first].0, groups[last].1);
+                if let Some(card_type) = self.confirm(&text[start..end]) {
+                    matches.push(PanMatch {
+                        start,
+                        end,
+                        card_type,
+                    });
+                    first = last + 1;
+                    continue 'groups;
+                }
+            }
+            first += 1;
+        }
+    }
+
+    fn confirm(&self, candidate: &str) -> Option<CreditCardType> {
+        let digits = candidate
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(char::from)
+            .collect::<String>();
+
+        let validation = self.pool.validate_card_number(digits).ok()?;
+        if validation.is_valid() {
+            validation.card_type
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the digit groups of the run starting at `start`, groups are separated by a single space or dash.
+fn digit_groups(bytes: &[u8], start: usize) -> Vec<(usize, usize)> {
+    let mut groups = Vec::new();
+    let mut group_start = start;
+    let mut position = start;
+
+    loop {
+        while bytes.get(position).is_some_and(u8::is_ascii_digit) {
+            position += 1;
+        }
+        groups.push((group_start, position));
+
+        let separated = matches!(bytes.get(position), Some(b' ' | b'-'))
+            && bytes.get(position + 1).is_some_and(u8::is_ascii_digit);
+        if !separated {
+            return groups;
+        }
+        position += 1;
+        group_start = position;
+    }
+}
+
+/// Returns `text` with the card numbers found in it masked.
+fn redact_matches(text: &str, pans: &[PanMatch]) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    let mut last_end = 0;
+
+    for pan in pans {
+        redacted.push_str(&text[last_end..pan.start]);
+        redacted.push_str(&mask_card_number(&text[pan.start..pan.end]));
+        last_end = pan.end;
+    }
+    redacted.push_str(&text[last_end..]);
+
+    redacted
+}
+
+/// Appends the next line to `buffer`, up to [`MAX_LINE_LEN`] bytes in total.
+///
+/// Returns `true` at the end of the input.
+fn read_line(reader: &mut impl BufRead, buffer: &mut Vec<u8>) -> io::Result<bool> {
+    while buffer.len() < MAX_LINE_LEN {
+        let available = match reader.fill_buf() {
+            Ok(available) => available,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        if available.is_empty() {
+            return Ok(true);
+        }
+
+        let available = &available[..available.len().min(MAX_LINE_LEN - buffer.len())];
+        let newline = available.iter().position(|byte| *byte == b'\n');
+        let consumed = newline.map_or(available.len(), |newline| newline + 1);
+        buffer.extend_from_slice(&available[..consumed]);
+        reader.consume(consumed);
+
+        if newline.is_some() {
+            break;
+        }
+    }
+
+    Ok(false)
+}
+
+/// Returns where to split the start of a line too long to hold in memory: after the last character that can't be
+/// part of a card number or the word before it, or at the last character boundary if there is none.
+fn split_point(buffer: &[u8]) -> usize {
+    let split = buffer
+        .iter()
+        .rposition(|byte| {
+            byte.is_ascii() && !byte.is_ascii_alphanumeric() && !matches!(byte, b' ' | b'-')
+        })
+        .map_or_else(
+            || core::str::from_utf8(buffer).map_or_else(|error| error.valid_up_to(), str::len),
+            |position| position + 1,
+        );
+
+    // Invalid UTF-8 at the start is reported by the caller.
+    if split == 0 {
+        buffer.len()
+    } else {
+        split
+    }
+}
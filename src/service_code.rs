@@ -0,0 +1,204 @@
+use core::{fmt, str::FromStr};
+
+use crate::errors::CardTypeError;
+
+/// The three digit service code of the magnetic stripe, which tells a terminal how the card may be used.
+///
+/// The first digit is the interchange and the technology of the card, the second one the authorization processing,
+/// and the third one the allowed services and the PIN requirement. Reserved values are kept, their flags are all
+/// `false`.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{Interchange, ServiceCode};
+///
+/// let service_code: ServiceCode = "201".parse().unwrap();
+///
+/// assert_eq!(service_code.interchange(), Interchange::International);
+/// assert!(service_code.has_chip());
+/// assert!(!service_code.requires_online_authorization());
+/// assert!(!service_code.requires_pin());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServiceCode([u8; 3]);
+
+impl ServiceCode {
+    /// Creates a service code from its digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::InvalidTrackData`] if a digit is over 9.
+    pub fn from_digits(digits: [u8; 3]) -> Result<ServiceCode, CardTypeError> {
+        if digits.iter().any(|digit| *digit > 9) {
+            return Err(CardTypeError::InvalidTrackData("invalid service code"));
+        }
+
+        Ok(ServiceCode(digits))
+    }
+
+    /// Returns the three digits.
+    #[must_use]
+    pub const fn digits(&self) -> [u8; 3] {
+        self.0
+    }
+
+    /// Returns where the card may be used, from the first digit.
+    #[must_use]
+    pub const fn interchange(&self) -> Interchange {
+        match self.0[0] {
+            1 | 2 => Interchange::International,
+            5 | 6 => Interchange::National,
+            7 => Interchange::Private,
+            9 => Interchange::Test,
+            _ => Interchange::Reserved,
+        }
+    }
+
+    /// Returns `true` if the card can be used abroad.
+    #[must_use]
+    pub const fn is_international(&self) -> bool {
+        matches!(self.interchange(), Interchange::International)
+    }
+
+    /// Returns `true` if the card has a chip, which should be used where the terminal supports it.
+    #[must_use]
+    pub const fn has_chip(&self) -> bool {
+        matches!(self.0[0], 2 | 6)
+    }
+
+    /// Returns how transactions are authorized, from the second digit.
+    #[must_use]
+    pub const fn authorization(&self) -> Authorization {
+        match self.0[1] {
+            0 => Authorization::Normal,
+            2 => Authorization::Online,
+            4 => Authorization::OnlineUnlessBilateral,
+            _ => Authorization::Reserved,
+        }
+    }
+
+    /// Returns `true` if every transaction must be authorized online by the issuer.
+    #[must_use]
+    pub const fn requires_online_authorization(&self) -> bool {
+        matches!(
+            self.authorization(),
+            Authorization::Online | Authorization::OnlineUnlessBilateral
+        )
+    }
+
+    /// Returns the services the card can be used for, from the third digit.
+    #[must_use]
+    pub const fn services(&self) -> Services {
+        match self.0[2] {
+            0 | 1 | 6 => Services::NoRestrictions,
+            2 | 5 | 7 => Services::GoodsAndServices,
+            3 => Services::AtmOnly,
+            4 => Services::CashOnly,
+            _ => Services::Reserved,
+        }
+    }
+
+    /// Returns `true` if the card can only be used at ATMs.
+    #[must_use]
+    pub const fn is_atm_only(&self) -> bool {
+        matches!(self.services(), Services::AtmOnly)
+    }
+
+    /// Returns when a PIN is asked, from the third digit.
+    #[must_use]
+    pub const fn pin(&self) -> PinRequirement {
+        match self.0[2] {
+            0 | 3 | 5 => PinRequirement::Required,
+            6 | 7 => PinRequirement::WhereFeasible,
+            1 | 2 | 4 => PinRequirement::None,
+            _ => PinRequirement::Reserved,
+        }
+    }
+
+    /// Returns `true` if a PIN is required for every transaction.
+    #[must_use]
+    pub const fn requires_pin(&self) -> bool {
+        matches!(self.pin(), PinRequirement::Required)
+    }
+}
+
+impl FromStr for ServiceCode {
+    type Err = CardTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[first, second, third] if s.bytes().all(|byte| byte.is_ascii_digit()) => {
+                ServiceCode::from_digits([first - b'0', second - b'0', third - b'0'])
+            }
+            _ => Err(CardTypeError::InvalidTrackData("invalid service code")),
+        }
+    }
+}
+
+impl fmt::Display for ServiceCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [first, second, third] = self.0;
+        write!(f, "{first}{second}{third}")
+    }
+}
+
+/// Where a card may be used, the first digit of the [`ServiceCode`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Interchange {
+    /// Everywhere, `1` and `2`.
+    International,
+    /// In the country of the issuer only, except under bilateral agreement, `5` and `6`.
+    National,
+    /// Nowhere except under bilateral agreement, like private label cards, `7`.
+    Private,
+    /// A test card, `9`.
+    Test,
+    /// A reserved value.
+    Reserved,
+}
+
+/// How transactions are authorized, the second digit of the [`ServiceCode`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Authorization {
+    /// According to the normal rules, `0`.
+    Normal,
+    /// Online by the issuer, `2`.
+    Online,
+    /// Online by the issuer, except under bilateral agreement, `4`.
+    OnlineUnlessBilateral,
+    /// A reserved value.
+    Reserved,
+}
+
+/// The services a card can be used for, from the third digit of the [`ServiceCode`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Services {
+    /// Goods, services and cash, `0`, `1` and `6`.
+    NoRestrictions,
+    /// Goods and services, no cash, `2`, `5` and `7`.
+    GoodsAndServices,
+    /// Cash at ATMs, `3`.
+    AtmOnly,
+    /// Cash, `4`.
+    CashOnly,
+    /// A reserved value.
+    Reserved,
+}
+
+/// When a PIN is asked, from the third digit of the [`ServiceCode`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PinRequirement {
+    /// For every transaction, `0`, `3` and `5`.
+    Required,
+    /// Where the terminal can read a PIN, `6` and `7`.
+    WhereFeasible,
+    /// Never, `1`, `2` and `4`.
+    None,
+    /// A reserved value.
+    Reserved,
+}
@@ -0,0 +1,178 @@
+use core::slice;
+
+use crate::{
+    card_types::{CreditCardType, DEFAULT_CARD_TYPES},
+    effective::Date,
+    errors::CardTypeError,
+    utils::{has_best_match, match_strength, match_width},
+};
+
+/// A pool of card types backed by a slice, for targets without an allocator.
+///
+/// It gives the same results as [`CreditCardPool`](crate::CreditCardPool), but detection never allocates: matches are
+/// returned as an iterator of card types instead of a `Vec`. The card types can be the built-in ones or any static
+/// slice.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{CardBrand, StaticCardPool};
+///
+/// let pool = StaticCardPool::new();
+///
+/// let mut matches = pool.get_credit_card_type("4111111111111111").unwrap();
+///
+/// assert_eq!(matches.next().unwrap().type_, CardBrand::Visa);
+/// assert!(matches.next().is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StaticCardPool<'a>(&'a [CreditCardType]);
+
+impl StaticCardPool<'static> {
+    /// Create a new `StaticCardPool` with the default credit card types.
+    #[must_use]
+    pub const fn new() -> StaticCardPool<'static> {
+        StaticCardPool(DEFAULT_CARD_TYPES)
+    }
+}
+
+impl<'a> StaticCardPool<'a> {
+    /// Create a new `StaticCardPool` with the given card types.
+    ///
+    /// If more than one card type has the same brand, all of them are used.
+    #[must_use]
+    pub const fn from_card_types(card_types: &'a [CreditCardType]) -> StaticCardPool<'a> {
+        StaticCardPool(card_types)
+    }
+
+    /// Returns an iterator over the card types that match the given card number.
+    ///
+    /// See [`CreditCardPool::get_credit_card_type`](crate::CreditCardPool::get_credit_card_type) for how card types
+    /// are matched.
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn get_credit_card_type<'b>(
+        &self,
+        card_number: &'b str,
+    ) -> Result<StaticMatches<'a, 'b>, CardTypeError> {
+        self.detect(card_number, None)
+    }
+
+    /// Returns an iterator over the card types that match the given card number, with the patterns in force on
+    /// `date`.
+    ///
+    /// See [`CreditCardPool::get_credit_card_type_on`](crate::CreditCardPool::get_credit_card_type_on).
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn get_credit_card_type_on<'b>(
+        &self,
+        card_number: &'b str,
+        date: Date,
+    ) -> Result<StaticMatches<'a, 'b>, CardTypeError> {
+        self.detect(card_number, Some(date))
+    }
+
+    fn detect<'b>(
+        &self,
+        card_number: &'b str,
+        date: Option<Date>,
+    ) -> Result<StaticMatches<'a, 'b>, CardTypeError> {
+        let mut number_of_results = 0;
+        let mut number_of_results_with_max_strength = 0;
+        let mut best_match: Option<CreditCardType> = None;
+        let mut best_match_width = u64::MAX;
+
+        if !card_number.is_empty() {
+            for card_type in self.0 {
+                let Some(match_strength) = match_strength(card_number, card_type, date)? else {
+                    continue;
+                };
+
+                number_of_results += 1;
+                if match_strength >= 1 {
+                    number_of_results_with_max_strength += 1;
+                }
+                // Between patterns of the same length, the one covering fewer prefixes is more specific.
+                let is_better = match &best_match {
+                    None => true,
+                    Some(best_match) => {
+                        match_strength > best_match.match_strength
+                            || (match_strength == best_match.match_strength
+                                && match_width(card_number, card_type, date)? < best_match_width)
+                    }
+                };
+                if is_better {
+                    best_match_width = match_width(card_number, card_type, date)?;
+                    best_match = Some(CreditCardType {
+                        match_strength,
+                        ..card_type.clone()
+                    });
+                }
+            }
+        }
+
+        if !has_best_match(number_of_results, number_of_results_with_max_strength) {
+            best_match = None;
+        }
+
+        Ok(StaticMatches {
+            card_number,
+            date,
+            card_types: self.0.iter(),
+            best_match,
+        })
+    }
+
+    /// Returns all card types in the pool.
+    #[must_use]
+    pub const fn card_types(&self) -> &'a [CreditCardType] {
+        self.0
+    }
+}
+
+impl Default for StaticCardPool<'static> {
+    fn default() -> Self {
+        StaticCardPool::new()
+    }
+}
+
+/// Iterator over the card types matching a card number, returned by [`StaticCardPool::get_credit_card_type`].
+#[derive(Debug, Clone)]
+pub struct StaticMatches<'a, 'b> {
+    card_number: &'b str,
+    date: Option<Date>,
+    card_types: slice::Iter<'a, CreditCardType>,
+    best_match: Option<CreditCardType>,
+}
+
+impl Iterator for StaticMatches<'_, '_> {
+    type Item = CreditCardType;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(best_match) = self.best_match.take() {
+            self.card_types = [].iter();
+            return Some(best_match);
+        }
+
+        for card_type in self.card_types.by_ref() {
+            if self.card_number.is_empty() {
+                return Some(card_type.clone());
+            }
+
+            // Errors were already returned by `StaticCardPool::get_credit_card_type`.
+            if let Ok(Some(match_strength)) = match_strength(self.card_number, card_type, self.date)
+            {
+                return Some(CreditCardType {
+                    match_strength,
+                    ..card_type.clone()
+                });
+            }
+        }
+
+        None
+    }
+}
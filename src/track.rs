@@ -0,0 +1,330 @@
+use alloc::{string::String, vec::Vec};
+use core::{fmt, mem, str::FromStr};
+
+use crate::{
+    card_types::{CreditCardPool, CreditCardType},
+    errors::CardTypeError,
+    pan::{zeroize_string, Pan},
+    service_code::ServiceCode,
+};
+
+/// The expiry date of a card, as stored on the magnetic stripe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Expiry {
+    /// The last two digits of the year.
+    pub year: u8,
+    /// The month, from 1 to 12.
+    pub month: u8,
+}
+
+impl Expiry {
+    /// Parses the `YYMM` format of the magnetic stripe.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::InvalidTrackData`] if `yymm` is not four digits with a month from 01 to 12.
+    pub fn from_yymm(yymm: &str) -> Result<Expiry, CardTypeError> {
+        let [year, month] = [yymm.get(..2), yymm.get(2..)].map(|digits| {
+            digits
+                .filter(|digits| {
+                    digits.len() == 2 && digits.bytes().all(|byte| byte.is_ascii_digit())
+                })
+                .and_then(|digits| digits.parse::<u8>().ok())
+        });
+
+        match (year, month) {
+            (Some(year), Some(month @ 1..=12)) => Ok(Expiry { year, month }),
+            _ => Err(CardTypeError::InvalidTrackData("invalid expiry date")),
+        }
+    }
+}
+
+impl fmt::Display for Expiry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}/{:02}", self.month, self.year)
+    }
+}
+
+/// The data of an ISO/IEC 7813 Track 1, like `%B4111111111111111^DOE/JOHN^2512101?`.
+///
+/// The start sentinel `%`, the end sentinel `?` and the LRC after it are optional, the LRC is checked when present.
+/// The discretionary data holds issuer secrets like the card verification value, so `Debug` leaves it out. The name
+/// and the discretionary data are wiped from memory when the track is dropped, like the card number.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{CardBrand, CreditCardPool, Track1};
+///
+/// let track: Track1 = "%B4111111111111111^DOE/JOHN^2512101123?".parse().unwrap();
+///
+/// assert_eq!(track.pan.last_four(), "1111");
+/// assert_eq!(track.name, "DOE/JOHN");
+/// assert_eq!(track.expiry.unwrap().to_string(), "12/25");
+///
+/// let pool = CreditCardPool::new();
+/// assert_eq!(track.card_types(&pool).unwrap()[0].type_, CardBrand::Visa);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Track1 {
+    /// The card number.
+    pub pan: Pan,
+    /// The cardholder name, usually `SURNAME/FIRST NAME`, without the trailing padding.
+    pub name: String,
+    /// The expiry date, `None` if the field is absent.
+    pub expiry: Option<Expiry>,
+    /// The service code, `None` if the field is absent.
+    pub service_code: Option<ServiceCode>,
+    /// The issuer's discretionary data.
+    pub discretionary_data: String,
+}
+
+impl Track1 {
+    /// Parses Track 1 data, see [`Track1`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::InvalidTrackData`] if a field is missing or malformed, or the LRC does not match.
+    /// Returns [`CardTypeError::InvalidCardNumber`] if the card number is not 1 to 19 digits.
+    pub fn parse(track: &str) -> Result<Track1, CardTypeError> {
+        let track = strip_sentinels(track, b'%', lrc_track1)?;
+        let track = track
+            .strip_prefix('B')
+            .ok_or(CardTypeError::InvalidTrackData("format code is not `B`"))?;
+
+        let mut fields = track.splitn(3, '^');
+        let (Some(pan), Some(name), Some(rest)) = (fields.next(), fields.next(), fields.next())
+        else {
+            return Err(CardTypeError::InvalidTrackData("missing field separator"));
+        };
+        let (expiry, service_code, discretionary_data) = parse_additional_data(rest, '^')?;
+
+        Ok(Track1 {
+            pan: parse_pan(pan)?,
+            name: String::from(name.trim_end()),
+            expiry,
+            service_code,
+            discretionary_data: String::from(discretionary_data),
+        })
+    }
+
+    /// Returns the card types matching the card number.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreditCardPool::get_credit_card_type`].
+    pub fn card_types(&self, pool: &CreditCardPool) -> Result<Vec<CreditCardType>, CardTypeError> {
+        pool.get_credit_card_type(&self.pan)
+    }
+}
+
+impl FromStr for Track1 {
+    type Err = CardTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Track1::parse(s)
+    }
+}
+
+impl Drop for Track1 {
+    fn drop(&mut self) {
+        zeroize_string(mem::take(&mut self.name));
+        zeroize_string(mem::take(&mut self.discretionary_data));
+    }
+}
+
+impl fmt::Debug for Track1 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Track1")
+            .field("pan", &self.pan)
+            .field("name", &self.name)
+            .field("expiry", &self.expiry)
+            .field("service_code", &self.service_code)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The data of an ISO/IEC 7813 Track 2, like `;4111111111111111=2512101?`.
+///
+/// The start sentinel `;`, the end sentinel `?` and the LRC after it are optional, the LRC is checked when present.
+/// The discretionary data holds issuer secrets like the card verification value, so `Debug` leaves it out. It is
+/// wiped from memory when the track is dropped, like the card number.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::{CardBrand, CreditCardPool, Track2};
+///
+/// let track: Track2 = ";378282246310005=2512101123?".parse().unwrap();
+///
+/// assert_eq!(track.pan.last_four(), "0005");
+/// assert!(track.service_code.unwrap().is_international());
+///
+/// let pool = CreditCardPool::new();
+/// assert_eq!(track.card_types(&pool).unwrap()[0].type_, CardBrand::AmericanExpress);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Track2 {
+    /// The card number.
+    pub pan: Pan,
+    /// The expiry date, `None` if the field is absent.
+    pub expiry: Option<Expiry>,
+    /// The service code, `None` if the field is absent.
+    pub service_code: Option<ServiceCode>,
+    /// The issuer's discretionary data.
+    pub discretionary_data: String,
+}
+
+impl Track2 {
+    /// Parses Track 2 data, see [`Track2`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CardTypeError::InvalidTrackData`] if a field is missing or malformed, or the LRC does not match.
+    /// Returns [`CardTypeError::InvalidCardNumber`] if the card number is not 1 to 19 digits.
+    pub fn parse(track: &str) -> Result<Track2, CardTypeError> {
+        let track = strip_sentinels(track, b';', lrc_track2)?;
+
+        let (pan, rest) = track
+            .split_once('=')
+            .ok_or(CardTypeError::InvalidTrackData("missing field separator"))?;
+        let (expiry, service_code, discretionary_data) = parse_additional_data(rest, '=')?;
+        if !discretionary_data.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(CardTypeError::InvalidTrackData(
+                "invalid discretionary data",
+            ));
+        }
+
+        Ok(Track2 {
+            pan: parse_pan(pan)?,
+            expiry,
+            service_code,
+            discretionary_data: String::from(discretionary_data),
+        })
+    }
+
+    /// Returns the card types matching the card number.
+    ///
+    /// # Errors
+    ///
+    /// See [`CreditCardPool::get_credit_card_type`].
+    pub fn card_types(&self, pool: &CreditCardPool) -> Result<Vec<CreditCardType>, CardTypeError> {
+        pool.get_credit_card_type(&self.pan)
+    }
+}
+
+impl FromStr for Track2 {
+    type Err = CardTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Track2::parse(s)
+    }
+}
+
+impl Drop for Track2 {
+    fn drop(&mut self) {
+        zeroize_string(mem::take(&mut self.discretionary_data));
+    }
+}
+
+impl fmt::Debug for Track2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Track2")
+            .field("pan", &self.pan)
+            .field("expiry", &self.expiry)
+            .field("service_code", &self.service_code)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Removes the start sentinel, and the end sentinel with the LRC after it, checking the LRC if there is one.
+fn strip_sentinels(
+    track: &str,
+    start_sentinel: u8,
+    lrc: fn(&[u8]) -> Option<u8>,
+) -> Result<&str, CardTypeError> {
+    let Some(end) = track.find('?') else {
+        return Ok(track
+            .strip_prefix(char::from(start_sentinel))
+            .unwrap_or(track));
+    };
+
+    match track.as_bytes()[end + 1..] {
+        [] => {}
+        [expected] => {
+            if track.as_bytes()[0] != start_sentinel {
+                return Err(CardTypeError::InvalidTrackData(
+                    "LRC without start sentinel",
+                ));
+            }
+            if lrc(&track.as_bytes()[..=end]) != Some(expected) {
+                return Err(CardTypeError::InvalidTrackData("LRC mismatch"));
+            }
+        }
+        _ => {
+            return Err(CardTypeError::InvalidTrackData(
+                "data after the end sentinel",
+            ))
+        }
+    }
+
+    let track = &track[..end];
+    Ok(track
+        .strip_prefix(char::from(start_sentinel))
+        .unwrap_or(track))
+}
+
+/// The LRC of Track 1, whose characters are encoded on 6 bits from `' '`.
+fn lrc_track1(track: &[u8]) -> Option<u8> {
+    lrc(track, b' ', b'_')
+}
+
+/// The LRC of Track 2, whose characters are encoded on 4 bits from `'0'`.
+fn lrc_track2(track: &[u8]) -> Option<u8> {
+    lrc(track, b'0', b'?')
+}
+
+/// XORs the characters between the sentinels included, encoded as their offset from `first`, and encodes the result
+/// the same way. `None` if a character is outside of `first..=last`.
+fn lrc(track: &[u8], first: u8, last: u8) -> Option<u8> {
+    track
+        .iter()
+        .try_fold(0, |lrc, byte| {
+            (first..=last).contains(byte).then(|| lrc ^ (byte - first))
+        })
+        .map(|lrc| lrc + first)
+}
+
+/// Parses the card number, which can not contain the spaces and dashes accepted by [`Pan`].
+fn parse_pan(pan: &str) -> Result<Pan, CardTypeError> {
+    if !pan.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(CardTypeError::InvalidCardNumber);
+    }
+
+    Pan::new(pan)
+}
+
+type AdditionalData<'a> = (Option<Expiry>, Option<ServiceCode>, &'a str);
+
+/// Parses the expiry date and the service code after the card number, each can be replaced by `separator` when absent.
+fn parse_additional_data(data: &str, separator: char) -> Result<AdditionalData<'_>, CardTypeError> {
+    let (expiry, data) = if let Some(data) = data.strip_prefix(separator) {
+        (None, data)
+    } else {
+        let yymm = data
+            .get(..4)
+            .ok_or(CardTypeError::InvalidTrackData("invalid expiry date"))?;
+        (Some(Expiry::from_yymm(yymm)?), &data[4..])
+    };
+
+    let (service_code, data) = if let Some(data) = data.strip_prefix(separator) {
+        (None, data)
+    } else {
+        let service_code = data
+            .get(..3)
+            .ok_or(CardTypeError::InvalidTrackData("invalid service code"))?;
+        (Some(service_code.parse()?), &data[3..])
+    };
+
+    Ok((expiry, service_code, data))
+}
@@ -1,4 +1,4 @@
-use crate::{errors::CardTypeError, CreditCardType};
+use crate::{effective::Date, errors::CardTypeError, CreditCardType};
 
 pub fn matches(card_number: &str, pattern: &'static [&'static str]) -> Result<bool, CardTypeError> {
     if pattern.len() == 1 {
@@ -13,63 +13,72 @@ fn matches_pattern(card_number: &str, pattern: &'static str) -> bool {
         == card_number.get(..pattern.len()).unwrap_or(card_number)
 }
 
-fn match_range(
-    card_number: &str,
-    min: &'static str,
-    max: &'static str,
-) -> Result<bool, CardTypeError> {
-    let mut max_len_to_check = max.len();
-    if max_len_to_check > card_number.len() {
-        max_len_to_check = card_number.len();
-    }
-    let str_slice = &card_number[..max_len_to_check];
-    let int_representation = str_slice.parse::<i32>()?;
-
-    let min = &min[..str_slice.len()];
-    let max = &max[..str_slice.len()];
+fn match_range(card_number: &str, min: &str, max: &str) -> Result<bool, CardTypeError> {
+    let length = max.len().min(card_number.len());
+    let number = &card_number.as_bytes()[..length];
+    let (Some(min), Some(max)) = (min.as_bytes().get(..length), max.as_bytes().get(..length))
+    else {
+        return Err(CardTypeError::InvalidCardNumber);
+    };
 
-    let min_int_representation = min.parse::<i32>()?;
-    let max_int_representation = max.parse::<i32>()?;
+    // Digit strings of the same length compare like the numbers they hold, however long they are.
+    if [number, min, max]
+        .iter()
+        .any(|digits| !digits.iter().all(u8::is_ascii_digit))
+    {
+        return Err(CardTypeError::InvalidCardNumber);
+    }
 
-    Ok(
-        int_representation >= min_int_representation
-            && int_representation <= max_int_representation,
-    )
+    Ok(min <= number && number <= max)
 }
 
-pub fn find_best_match<'a>(results: &[&'a CreditCardType]) -> Option<&'a CreditCardType> {
-    if !can_determine_best_match(results) {
-        return None;
-    }
-
-    let mut best_match_result: Option<&CreditCardType> = None;
+/// A best match can only be chosen if more than one card type matched, and all of them with a strength of at least 1.
+pub fn has_best_match(
+    number_of_results: usize,
+    number_of_results_with_max_strength: usize,
+) -> bool {
+    number_of_results_with_max_strength > 1
+        && number_of_results_with_max_strength == number_of_results
+}
 
-    for card_type in results {
-        if best_match_result.is_none()
-            || card_type.match_strength > best_match_result.as_ref()?.match_strength
-        {
-            best_match_result = Some(card_type);
+/// Returns the number of prefixes covered by the first pattern of `card_type` matching `card_number`, `1` for a single
+/// prefix. Among patterns of the same length, a narrower one is more specific.
+pub fn match_width(
+    card_number: &str,
+    card_type: &CreditCardType,
+    date: Option<Date>,
+) -> Result<u64, CardTypeError> {
+    for pattern in in_force(card_type, date) {
+        if !matches(card_number, pattern)? {
+            continue;
         }
-    }
 
-    best_match_result
-}
+        if pattern.len() == 1 {
+            return Ok(1);
+        }
 
-fn can_determine_best_match(results: &[&CreditCardType]) -> bool {
-    let number_of_results_with_max_strength = results
-        .iter()
-        .filter(|card_type| card_type.match_strength >= 1)
-        .count();
+        // Ranges too wide for a `u64` are wider than any other.
+        let width = match (pattern[0].parse::<u128>(), pattern[1].parse::<u128>()) {
+            (Ok(min), Ok(max)) => max.saturating_sub(min).saturating_add(1),
+            _ => u128::MAX,
+        };
+        return Ok(u64::try_from(width).unwrap_or(u64::MAX));
+    }
 
-    number_of_results_with_max_strength > 1 && number_of_results_with_max_strength == results.len()
+    Ok(u64::MAX)
 }
 
-pub fn add_best_match_to_results<'a>(
+/// Returns the match strength of the first pattern of `card_type` matching `card_number`, or `None` if no pattern
+/// matches.
+///
+/// The strength is the length of the pattern, if the card number is at least as long, otherwise the card type's
+/// current `match_strength` is kept.
+pub fn match_strength(
     card_number: &str,
-    card_type: &'a mut CreditCardType,
-    results: &mut Vec<&'a CreditCardType>,
-) -> Result<(), CardTypeError> {
-    for pattern in card_type.patterns {
+    card_type: &CreditCardType,
+    date: Option<Date>,
+) -> Result<Option<u32>, CardTypeError> {
+    for pattern in in_force(card_type, date) {
         if !matches(card_number, pattern)? {
             continue;
         }
@@ -77,12 +86,23 @@ pub fn add_best_match_to_results<'a>(
         let pattern_length = pattern[0].len();
 
         if card_number.len() >= pattern_length {
-            card_type.match_strength = u32::try_from(pattern_length)?;
+            return Ok(Some(u32::try_from(pattern_length)?));
         }
 
-        results.push(card_type);
-        break;
+        return Ok(Some(card_type.match_strength));
     }
 
-    Ok(())
+    Ok(None)
+}
+
+/// Returns the patterns of `card_type` in force on `date`, all of them for `None`.
+fn in_force(
+    card_type: &CreditCardType,
+    date: Option<Date>,
+) -> impl Iterator<Item = &'static [&'static str]> + '_ {
+    card_type
+        .patterns
+        .iter()
+        .copied()
+        .filter(move |pattern| card_type.is_in_force(pattern, date))
 }
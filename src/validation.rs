@@ -0,0 +1,126 @@
+use crate::card_types::CreditCardType;
+#[cfg(feature = "alloc")]
+use crate::{errors::CardTypeError, CreditCardPool};
+
+/// The result of validating a card number with `CreditCardPool::validate_card_number`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Validation {
+    /// The card type of the number, `None` if no card type or more than one card type matches.
+    pub card_type: Option<CreditCardType>,
+    /// Whether the number passes the Luhn checksum.
+    pub luhn_valid: bool,
+    /// Whether the length of the number is allowed by the card type.
+    pub length_valid: bool,
+}
+
+impl Validation {
+    /// Returns `true` if the number has a single card type, a valid length and a valid checksum.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.card_type.is_some() && self.luhn_valid && self.length_valid
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CreditCardPool {
+    /// Validates a full card number against the card types of the pool and the Luhn checksum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credit_card_types::CreditCardPool;
+    ///
+    /// let pool = CreditCardPool::new();
+    ///
+    /// assert!(pool.validate_card_number("4111111111111111").unwrap().is_valid());
+    /// assert!(!pool.validate_card_number("4111111111111112").unwrap().is_valid());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the card number is invalid, it will return an error. If you pass a letter for example, it will return an error.
+    pub fn validate_card_number(
+        &self,
+        card_number: impl AsRef<str>,
+    ) -> Result<Validation, CardTypeError> {
+        let card_number = card_number.as_ref();
+
+        let mut card_types = self.get_credit_card_type(card_number)?;
+        let card_type = if card_number.is_empty() || card_types.len() != 1 {
+            None
+        } else {
+            card_types.pop()
+        };
+
+        let length_valid = card_type.as_ref().is_some_and(|card_type| {
+            u32::try_from(card_number.len()).is_ok_and(|length| card_type.lengths.contains(&length))
+        });
+
+        Ok(Validation {
+            card_type,
+            luhn_valid: luhn_check(card_number),
+            length_valid,
+        })
+    }
+}
+
+/// Checks a card number against the Luhn checksum.
+///
+/// Returns `false` if the number is empty or contains anything other than ASCII digits.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::luhn_check;
+///
+/// assert!(luhn_check("4111111111111111"));
+/// assert!(!luhn_check("4111111111111112"));
+/// ```
+#[must_use]
+pub fn luhn_check(card_number: &str) -> bool {
+    if card_number.is_empty() || !card_number.bytes().all(|byte| byte.is_ascii_digit()) {
+        return false;
+    }
+
+    luhn_sum(card_number.bytes().rev(), false).is_multiple_of(10)
+}
+
+/// Computes the Luhn check digit that has to be appended to `partial_number` to make it valid.
+///
+/// Returns `None` if the number contains anything other than ASCII digits.
+///
+/// # Example
+///
+/// ```
+/// use credit_card_types::luhn_check_digit;
+///
+/// assert_eq!(luhn_check_digit("411111111111111"), Some(1));
+/// ```
+#[must_use]
+pub fn luhn_check_digit(partial_number: &str) -> Option<u8> {
+    if !partial_number.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let sum = luhn_sum(partial_number.bytes().rev(), true);
+    u8::try_from((10 - sum % 10) % 10).ok()
+}
+
+fn luhn_sum(reversed_digits: impl Iterator<Item = u8>, double_first: bool) -> u32 {
+    reversed_digits
+        .enumerate()
+        .map(|(index, byte)| {
+            let digit = u32::from(byte - b'0');
+            if (index % 2 == 0) == double_first {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum()
+}
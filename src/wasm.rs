@@ -0,0 +1,263 @@
+//! WebAssembly bindings, enabled by the `wasm` feature.
+//!
+//! The same detection, validation and formatting rules as the Rust API, exported to JavaScript with `wasm-bindgen`:
+//!
+//! ```js
+//! import { CreditCardPool } from "credit_card_types";
+//!
+//! const pool = new CreditCardPool();
+//!
+//! const [cardType] = pool.getCreditCardType("4111111111111111");
+//! console.log(cardType.type, cardType.niceType, cardType.gaps, cardType.code.size);
+//!
+//! const validation = pool.validateCardNumber("4111 1111 1111 1111");
+//! console.log(validation.isValid, pool.formatCardNumber("4111111111111111"));
+//! ```
+//!
+//! Card numbers may contain spaces and dashes. Invalid input throws an `Error`.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    card_types::{Code, CreditCardPool, CreditCardType},
+    errors::CardTypeError,
+    formatting::mask_card_number,
+    validation::{luhn_check, Validation},
+};
+
+/// A pool of card types, `CreditCardPool` in JavaScript.
+#[wasm_bindgen(js_name = CreditCardPool)]
+#[derive(Debug, Clone, Default)]
+pub struct WasmCreditCardPool(CreditCardPool);
+
+#[wasm_bindgen(js_class = CreditCardPool)]
+impl WasmCreditCardPool {
+    /// Creates a pool with the default card types.
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    pub fn new() -> WasmCreditCardPool {
+        WasmCreditCardPool(CreditCardPool::new())
+    }
+
+    /// Returns the card types matching a full or partial card number.
+    ///
+    /// # Errors
+    ///
+    /// Throws if the card number contains anything other than digits, spaces and dashes.
+    #[wasm_bindgen(js_name = getCreditCardType)]
+    pub fn get_credit_card_type(&self, card_number: &str) -> Result<Vec<WasmCardType>, JsError> {
+        let card_types = self
+            .0
+            .get_credit_card_type(normalize(card_number)?)
+            .map_err(JsError::from)?;
+
+        Ok(card_types.into_iter().map(WasmCardType).collect())
+    }
+
+    /// Validates a full card number.
+    ///
+    /// # Errors
+    ///
+    /// Throws if the card number contains anything other than digits, spaces and dashes.
+    #[wasm_bindgen(js_name = validateCardNumber)]
+    pub fn validate_card_number(&self, card_number: &str) -> Result<WasmValidation, JsError> {
+        self.0
+            .validate_card_number(normalize(card_number)?)
+            .map(WasmValidation)
+            .map_err(JsError::from)
+    }
+
+    /// Formats a card number with the gaps of its card type, the digits are returned as they are if the card type is
+    /// unknown or ambiguous.
+    ///
+    /// # Errors
+    ///
+    /// Throws if the card number contains anything other than digits, spaces and dashes.
+    #[wasm_bindgen(js_name = formatCardNumber)]
+    pub fn format_card_number(&self, card_number: &str) -> Result<String, JsError> {
+        let card_number = normalize(card_number)?;
+        let card_types = self
+            .0
+            .get_credit_card_type(&card_number)
+            .map_err(JsError::from)?;
+
+        Ok(match card_types.as_slice() {
+            [card_type] => card_type.format_card_number(&card_number),
+            _ => card_number,
+        })
+    }
+}
+
+/// A card type returned by detection, `CardType` in JavaScript.
+#[wasm_bindgen(js_name = CardType)]
+#[derive(Debug, Clone)]
+pub struct WasmCardType(CreditCardType);
+
+#[wasm_bindgen(js_class = CardType)]
+impl WasmCardType {
+    /// The brand identifier, e.g. `"american-express"`.
+    #[wasm_bindgen(getter, js_name = type)]
+    #[must_use]
+    pub fn type_(&self) -> String {
+        self.0.type_.to_string()
+    }
+
+    /// The display name, e.g. `"American Express"`.
+    #[wasm_bindgen(getter, js_name = niceType)]
+    #[must_use]
+    pub fn nice_type(&self) -> String {
+        self.0.nice_type.to_string()
+    }
+
+    /// Positions of the spaces in a formatted number.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn gaps(&self) -> Vec<u32> {
+        self.0.gaps.to_vec()
+    }
+
+    /// Allowed lengths of a full number.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn lengths(&self) -> Vec<u32> {
+        self.0.lengths.to_vec()
+    }
+
+    /// The largest allowed length of a full number.
+    #[wasm_bindgen(getter, js_name = maxLength)]
+    #[must_use]
+    pub fn max_length(&self) -> u32 {
+        self.0.max_length()
+    }
+
+    /// An input mask like `"#### ###### #####"`, with a `#` for each digit.
+    #[wasm_bindgen(getter, js_name = inputMask)]
+    #[must_use]
+    pub fn input_mask(&self) -> String {
+        self.0.input_mask()
+    }
+
+    /// The `maxlength` of a text input holding a formatted number.
+    #[wasm_bindgen(getter, js_name = maxInputLength)]
+    #[must_use]
+    pub fn max_input_length(&self) -> usize {
+        self.0.max_input_length()
+    }
+
+    /// The security code on the back of the card.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn code(&self) -> WasmCode {
+        WasmCode(self.0.code.clone())
+    }
+
+    /// How specific the matched pattern is, longer patterns are stronger.
+    #[wasm_bindgen(getter, js_name = matchStrength)]
+    #[must_use]
+    pub fn match_strength(&self) -> u32 {
+        self.0.match_strength
+    }
+}
+
+/// The security code of a card type, `CardCode` in JavaScript.
+#[wasm_bindgen(js_name = CardCode)]
+#[derive(Debug, Clone)]
+pub struct WasmCode(Code);
+
+#[wasm_bindgen(js_class = CardCode)]
+impl WasmCode {
+    /// The name of the code, e.g. `"CVV"`.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.0.name.to_string()
+    }
+
+    /// The number of digits.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn size(&self) -> u32 {
+        self.0.size
+    }
+
+    /// An input mask like `"###"`, empty if the card has no code.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn mask(&self) -> String {
+        self.0.mask()
+    }
+}
+
+/// The result of validating a card number, `Validation` in JavaScript.
+#[wasm_bindgen(js_name = Validation)]
+#[derive(Debug, Clone)]
+pub struct WasmValidation(Validation);
+
+#[wasm_bindgen(js_class = Validation)]
+impl WasmValidation {
+    /// Whether the number has a single card type, a valid length and a valid checksum.
+    #[wasm_bindgen(getter, js_name = isValid)]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    /// Whether the number passes the Luhn checksum.
+    #[wasm_bindgen(getter, js_name = luhnValid)]
+    #[must_use]
+    pub fn luhn_valid(&self) -> bool {
+        self.0.luhn_valid
+    }
+
+    /// Whether the length of the number is allowed by the card type.
+    #[wasm_bindgen(getter, js_name = lengthValid)]
+    #[must_use]
+    pub fn length_valid(&self) -> bool {
+        self.0.length_valid
+    }
+
+    /// The card type, `undefined` if no card type or more than one card type matches.
+    #[wasm_bindgen(getter, js_name = cardType)]
+    #[must_use]
+    pub fn card_type(&self) -> Option<WasmCardType> {
+        self.0.card_type.clone().map(WasmCardType)
+    }
+}
+
+/// Masks a card number, keeping only the last four digits visible.
+#[wasm_bindgen(js_name = maskCardNumber)]
+#[must_use]
+pub fn wasm_mask_card_number(card_number: &str) -> String {
+    mask_card_number(card_number)
+}
+
+/// Checks a card number against the Luhn checksum, spaces and dashes are ignored.
+#[wasm_bindgen(js_name = luhnCheck)]
+#[must_use]
+pub fn wasm_luhn_check(card_number: &str) -> bool {
+    let card_number = card_number
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '-'))
+        .collect::<String>();
+
+    luhn_check(&card_number)
+}
+
+/// Removes spaces and dashes, the form input is passed as typed.
+fn normalize(card_number: &str) -> Result<String, JsError> {
+    let card_number = card_number
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '-'))
+        .collect::<String>();
+
+    if card_number.bytes().all(|byte| byte.is_ascii_digit()) {
+        Ok(card_number)
+    } else {
+        Err(JsError::from(CardTypeError::InvalidCardNumber))
+    }
+}
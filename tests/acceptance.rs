@@ -0,0 +1,144 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    Acceptance, AcceptancePolicy, CardBrand, CardRange, CreditCardPool, CreditCardType,
+    FundingType, Rejection, DEFAULT_CARD_TYPES,
+};
+
+fn card_type(brand: CardBrand) -> CreditCardType {
+    DEFAULT_CARD_TYPES
+        .iter()
+        .find(|card_type| card_type.type_ == brand)
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn test_accepted_brand() {
+    let pool = CreditCardPool::new();
+    let policy = AcceptancePolicy::new([CardBrand::Visa, CardBrand::Mastercard]);
+
+    assert_eq!(
+        policy.check(&pool, "4111111111111111").unwrap(),
+        Acceptance::Accepted {
+            network: CardBrand::Visa,
+            funding: FundingType::Unknown,
+            routed: false,
+        }
+    );
+    assert!(policy.check(&pool, "5555").unwrap().is_accepted());
+}
+
+#[test]
+fn test_rejection_reasons() {
+    let pool = CreditCardPool::new();
+    let policy = AcceptancePolicy::new([CardBrand::Visa, CardBrand::Mastercard]);
+
+    let rejection = policy.check(&pool, "378282246310005").unwrap().rejection();
+    assert_eq!(
+        rejection,
+        Some(Rejection::UnsupportedBrand {
+            brand: CardBrand::AmericanExpress,
+            nice_type: "American Express",
+        })
+    );
+    assert_eq!(
+        rejection.unwrap().to_string(),
+        "American Express cards are not accepted."
+    );
+
+    let rejection = policy.check(&pool, "7").unwrap().rejection();
+    assert_eq!(rejection, Some(Rejection::Unknown));
+    assert_eq!(
+        rejection.unwrap().to_string(),
+        "This card number is not recognized."
+    );
+
+    let rejection = policy.check(&pool, "").unwrap().rejection();
+    assert_eq!(rejection, Some(Rejection::Ambiguous));
+    assert_eq!(
+        rejection.unwrap().to_string(),
+        "Enter the full card number."
+    );
+
+    assert!(policy.check(&pool, "4111a").is_err());
+}
+
+#[test]
+fn test_co_badged_routing() {
+    let pool = CreditCardPool::new();
+    let policy = AcceptancePolicy::new([CardBrand::Discover]);
+
+    // Elo/Discover is rejected unless the card may be routed to Discover.
+    assert!(!policy
+        .check(&pool, "6504050000000000")
+        .unwrap()
+        .is_accepted());
+    assert_eq!(
+        policy
+            .with_co_badged_routing(true)
+            .check(&pool, "6504050000000000")
+            .unwrap(),
+        Acceptance::Accepted {
+            network: CardBrand::Discover,
+            funding: FundingType::Unknown,
+            routed: true,
+        }
+    );
+
+    // The primary brand is preferred when both are accepted.
+    let policy =
+        AcceptancePolicy::new([CardBrand::Elo, CardBrand::Discover]).with_co_badged_routing(true);
+    assert_eq!(
+        policy.check(&pool, "6504050000000000").unwrap(),
+        Acceptance::Accepted {
+            network: CardBrand::Elo,
+            funding: FundingType::Unknown,
+            routed: false,
+        }
+    );
+}
+
+#[test]
+fn test_funding_types() {
+    const RANGES: &[CardRange] = &[
+        CardRange::new(&["411111"]).with_funding(FundingType::Prepaid),
+        CardRange::new(&["400000"]).with_funding(FundingType::Debit),
+    ];
+
+    let mut pool = CreditCardPool::new();
+    pool.insert_card_type(CreditCardType {
+        ranges: RANGES,
+        ..card_type(CardBrand::Visa)
+    });
+
+    let policy = AcceptancePolicy::new([CardBrand::Visa]).without_funding(FundingType::Prepaid);
+    let rejection = policy.check(&pool, "4111111111111111").unwrap().rejection();
+    assert_eq!(
+        rejection,
+        Some(Rejection::UnsupportedFunding(FundingType::Prepaid))
+    );
+    assert_eq!(
+        rejection.unwrap().to_string(),
+        "Prepaid cards are not accepted."
+    );
+    assert!(policy
+        .check(&pool, "4000000000000002")
+        .unwrap()
+        .is_accepted());
+    assert!(policy
+        .check(&pool, "4242424242424242")
+        .unwrap()
+        .is_accepted());
+
+    // Cards without funding information are rejected unless `Unknown` is allowed.
+    let policy = AcceptancePolicy::new([CardBrand::Visa]).with_funding([FundingType::Debit]);
+    assert!(policy
+        .check(&pool, "4000000000000002")
+        .unwrap()
+        .is_accepted());
+    assert_eq!(
+        policy.check(&pool, "4242424242424242").unwrap().rejection(),
+        Some(Rejection::UnsupportedFunding(FundingType::Unknown))
+    );
+}
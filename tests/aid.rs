@@ -0,0 +1,127 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    AidEntry, AidRegistry, BrandCheck, CardBrand, CreditCardPool, EmvData, DEFAULT_AIDS,
+};
+
+const VISA_ELECTRON: &[u8] = &[0xA0, 0x00, 0x00, 0x00, 0x03, 0x20, 0x10];
+const MAESTRO: &[u8] = &[0xA0, 0x00, 0x00, 0x00, 0x04, 0x30, 0x60];
+
+#[test]
+fn test_default_aids() {
+    assert!(DEFAULT_AIDS
+        .windows(2)
+        .all(|pair| pair[0].prefix < pair[1].prefix));
+    assert!(DEFAULT_AIDS.iter().all(|entry| entry.rid().len() == 5));
+
+    let registry = AidRegistry::new();
+    assert_eq!(registry.aids(), DEFAULT_AIDS);
+    assert_eq!(registry, AidRegistry::default());
+
+    let aid_tests = [
+        (VISA_ELECTRON, CardBrand::Visa, "Visa Electron"),
+        (
+            &[0xA0, 0x00, 0x00, 0x00, 0x03, 0x10, 0x10, 0x01][..],
+            CardBrand::Visa,
+            "Visa Credit/Debit",
+        ),
+        (
+            &[0xA0, 0x00, 0x00, 0x00, 0x03, 0x99, 0x99],
+            CardBrand::Visa,
+            "Visa",
+        ),
+        (MAESTRO, CardBrand::Maestro, "Maestro"),
+        (
+            &[0xA0, 0x00, 0x00, 0x00, 0x04, 0x10, 0x10],
+            CardBrand::Mastercard,
+            "Mastercard Credit/Debit",
+        ),
+        (
+            &[0xA0, 0x00, 0x00, 0x00, 0x25, 0x01, 0x08, 0x01],
+            CardBrand::AmericanExpress,
+            "American Express",
+        ),
+    ];
+    for (aid, brand, product) in aid_tests {
+        let entry = registry.lookup(aid).unwrap();
+        assert_eq!(entry.brand, brand, "Failed for {:02X?}", aid);
+        assert_eq!(entry.product, product, "Failed for {:02X?}", aid);
+    }
+
+    assert_eq!(registry.lookup(&[0xA0, 0x00, 0x00, 0x09, 0x99]), None);
+    assert_eq!(registry.lookup(&[0xA0, 0x00]), None);
+
+    let entry = registry.lookup(VISA_ELECTRON).unwrap();
+    assert_eq!(entry.rid(), [0xA0, 0x00, 0x00, 0x00, 0x03]);
+    assert_eq!(entry.pix(), [0x20, 0x10]);
+}
+
+#[test]
+fn test_custom_aids() {
+    const FLEET: &[u8] = &[0xA0, 0x00, 0x00, 0x09, 0x99, 0x10, 0x10];
+
+    let mut registry = AidRegistry::new();
+    registry.insert_aid(AidEntry::new(FLEET, CardBrand::Custom("fleet"), "Fleet"));
+    registry.insert_aid(AidEntry::new(
+        MAESTRO,
+        CardBrand::Mastercard,
+        "Debit Mastercard",
+    ));
+    registry.remove_aid(VISA_ELECTRON);
+
+    assert!(registry
+        .aids()
+        .windows(2)
+        .all(|pair| pair[0].prefix < pair[1].prefix));
+    assert_eq!(registry.aids().len(), DEFAULT_AIDS.len());
+    assert_eq!(registry.brand(FLEET), Some(CardBrand::Custom("fleet")));
+    assert_eq!(registry.brand(MAESTRO), Some(CardBrand::Mastercard));
+    assert_eq!(registry.lookup(VISA_ELECTRON).unwrap().product, "Visa");
+
+    let registry = AidRegistry::new_empty();
+    assert!(registry.aids().is_empty());
+    assert_eq!(registry.lookup(VISA_ELECTRON), None);
+}
+
+#[test]
+fn test_card_type_of_aid() {
+    let registry = AidRegistry::new();
+    let pool = CreditCardPool::new();
+
+    let card_type = registry.card_type(MAESTRO, &pool).unwrap();
+    assert_eq!(card_type.type_, CardBrand::Maestro);
+    assert_eq!(card_type.lengths, [12, 13, 14, 15, 16, 17, 18, 19]);
+
+    // Bancontact is in the `region-eu` pack.
+    let bancontact = &[0xD0, 0x56, 0x00, 0x06, 0x66, 0x11, 0x10, 0x10];
+    assert_eq!(registry.brand(bancontact), Some(CardBrand::Bancontact));
+    assert_eq!(registry.card_type(bancontact, &pool), None);
+    assert_eq!(
+        registry.card_type(&[0xA0, 0x00, 0x00, 0x09, 0x99], &pool),
+        None
+    );
+}
+
+#[test]
+fn test_emv_data_with_custom_aids() {
+    let data = [
+        0x9F, 0x06, 0x07, 0xA0, 0x00, 0x00, 0x09, 0x99, 0x10, 0x10, // AID
+        0x5A, 0x08, 0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, // PAN
+    ];
+    let emv_data = EmvData::parse(&data).unwrap();
+    let pool = CreditCardPool::new();
+
+    assert_eq!(emv_data.aid_network(), None);
+    assert_eq!(emv_data.check_brand(&pool).unwrap(), BrandCheck::Unknown);
+
+    let mut registry = AidRegistry::new();
+    registry.insert_aid(AidEntry::new(
+        &[0xA0, 0x00, 0x00, 0x09, 0x99],
+        CardBrand::Visa,
+        "Visa Fleet",
+    ));
+    assert_eq!(
+        emv_data.check_brand_with(&pool, &registry).unwrap(),
+        BrandCheck::Consistent(CardBrand::Visa)
+    );
+}
@@ -1,6 +1,11 @@
+#![cfg(feature = "alloc")]
+
 use core::fmt::Debug;
 use core::hash::Hash;
-use credit_card_types::{Code, CreditCardPool, CreditCardType};
+use credit_card_types::{
+    luhn_check, luhn_check_digit, mask_card_number, CardBrand, Code, CreditCardPool,
+    CreditCardType, DEFAULT_CARD_TYPES,
+};
 
 enum Matcher {
     CardNumber(&'static str),
@@ -37,11 +42,13 @@ fn implement_all_traits() {
     assert_common_traits::<CreditCardPool>();
     assert_common_traits::<CreditCardType>();
     assert_common_traits::<Code>();
+    assert_common_traits::<CardBrand>();
 
     fn assert_is_normal<T: Send + Sync + Sized + Unpin>() {}
     assert_is_normal::<CreditCardPool>();
     assert_is_normal::<CreditCardType>();
     assert_is_normal::<Code>();
+    assert_is_normal::<CardBrand>();
 
     fn assert_default<T: Default>() {}
     assert_default::<CreditCardPool>();
@@ -426,10 +433,12 @@ fn test_card_ccv() {
 fn test_add_custom_card() {
     let mut card_types = CreditCardPool::new();
     let card_type = CreditCardType {
-        type_: "custom",
+        type_: CardBrand::Custom("custom"),
         match_strength: 0,
         nice_type: "Custom",
         patterns: &[&["1234567890123456"]],
+        validity: &[],
+        ranges: &[],
         lengths: &[16],
         gaps: &[4, 8, 12],
         code: Code {
@@ -444,6 +453,194 @@ fn test_add_custom_card() {
     assert_eq!(card_types[0].match_strength, 16);
 }
 
+#[test]
+fn test_card_brand_from_str_and_display() {
+    for brand in CardBrand::BUILT_IN {
+        let parsed: CardBrand = brand.to_string().parse().unwrap();
+        assert_eq!(parsed, *brand);
+    }
+
+    assert_eq!("hiper".parse::<CardBrand>().unwrap(), CardBrand::Hiper);
+    assert!("hyper".parse::<CardBrand>().is_err());
+    assert!("custom".parse::<CardBrand>().is_err());
+    assert_eq!(CardBrand::Custom("visa"), CardBrand::Visa);
+}
+
+#[test]
+fn test_remove_card_type() {
+    let mut card_types = CreditCardPool::new();
+
+    card_types.remove_card_type(CardBrand::Hiper);
+    card_types.remove_card_type(CardBrand::Hipercard);
+
+    let all_brands = card_types
+        .get_all_card_types()
+        .iter()
+        .map(|card| card.type_)
+        .collect::<Vec<_>>();
+    assert!(!all_brands.contains(&CardBrand::Hiper));
+    assert!(!all_brands.contains(&CardBrand::Hipercard));
+    assert_eq!(all_brands.len(), DEFAULT_CARD_TYPES.len() - 2);
+
+    let card_type = card_types.get_credit_card_type("637095").unwrap();
+    assert_eq!(card_type[0].type_, CardBrand::Maestro);
+}
+
+#[test]
+fn test_validate_card_numbers() {
+    let card_tests = [
+        ("4111111111111111", true),
+        ("4012888888881881", true),
+        ("378282246310005", true),
+        ("6011000990139424", true),
+        ("5555555555554444", true),
+        ("4111111111111112", false),
+        ("41111111111111", false),
+        ("378282246310005000", false),
+        ("4", false),
+        ("", false),
+    ];
+
+    let card_types = CreditCardPool::new();
+
+    for (card_number, valid) in card_tests {
+        let validation = card_types.validate_card_number(card_number).unwrap();
+        assert_eq!(validation.is_valid(), valid, "Failed for {}", card_number);
+    }
+
+    assert!(!luhn_check("4111 1111 1111 1111"));
+    assert_eq!(luhn_check_digit("37828224631000"), Some(5));
+    assert_eq!(luhn_check_digit("4a"), None);
+}
+
+#[test]
+fn test_format_and_mask_card_numbers() {
+    let card_tests = [
+        ("4111111111111111", "4111 1111 1111 1111"),
+        ("378282246310005", "3782 822463 10005"),
+        ("30569309025904", "3056 930902 5904"),
+        ("4111-1111-1111-1111", "4111 1111 1111 1111"),
+        ("41111", "4111 1"),
+    ];
+
+    let card_types = CreditCardPool::new();
+
+    for (card_number, formatted) in card_tests {
+        let card_type = &card_types.get_credit_card_type(&card_number[..2]).unwrap()[0];
+        assert_eq!(
+            card_type.format_card_number(card_number),
+            formatted,
+            "Failed for {}",
+            card_number
+        );
+    }
+
+    assert_eq!(mask_card_number("4111111111111111"), "************1111");
+    assert_eq!(mask_card_number("3782-822463-10005"), "****-******-*0005");
+    assert_eq!(mask_card_number("411"), "411");
+}
+
+#[test]
+fn test_input_masks() {
+    let card_tests = [
+        ("4111111111111111", "#### #### #### #######", 19, 22, "###"),
+        ("378282246310005", "#### ###### #####", 15, 17, "####"),
+        ("30569309025904", "#### ###### #########", 19, 21, "###"),
+        ("5555555555554444", "#### #### #### ####", 16, 19, "###"),
+    ];
+
+    let card_types = CreditCardPool::new();
+
+    for (card_number, input_mask, max_length, max_input_length, code_mask) in card_tests {
+        let card_type = &card_types.get_credit_card_type(card_number).unwrap()[0];
+        assert_eq!(
+            card_type.input_mask(),
+            input_mask,
+            "Failed for {}",
+            card_number
+        );
+        assert_eq!(
+            card_type.max_length(),
+            max_length,
+            "Failed for {}",
+            card_number
+        );
+        assert_eq!(
+            card_type.max_input_length(),
+            max_input_length,
+            "Failed for {}",
+            card_number
+        );
+        assert_eq!(
+            card_type.code.mask(),
+            code_mask,
+            "Failed for {}",
+            card_number
+        );
+    }
+
+    let card_type = CreditCardType {
+        lengths: &[],
+        code: Code {
+            name: "CVV",
+            size: 0,
+        },
+        ..CreditCardType::default()
+    };
+    assert_eq!(card_type.input_mask(), "");
+    assert_eq!(card_type.max_length(), 0);
+    assert_eq!(card_type.code.mask(), "");
+}
+
+#[test]
+fn test_const_default_pool() {
+    static POOL: CreditCardPool = CreditCardPool::new();
+    const EMPTY: CreditCardPool = CreditCardPool::new_empty();
+
+    assert_eq!(POOL, CreditCardPool::default());
+    assert_eq!(POOL.card_types(), DEFAULT_CARD_TYPES);
+    assert!(EMPTY.get_all_card_types().is_empty());
+    assert!(DEFAULT_CARD_TYPES
+        .windows(2)
+        .all(|pair| pair[0].type_ < pair[1].type_));
+
+    let card_type = POOL.get_credit_card_type("4111111111111111").unwrap();
+    assert_eq!(card_type[0].type_, CardBrand::Visa);
+}
+
+#[test]
+fn test_modified_pool_stays_sorted() {
+    let mut card_types = CreditCardPool::new();
+    card_types.insert_card_type(CreditCardType {
+        type_: CardBrand::Custom("aaa"),
+        ..CreditCardType::default()
+    });
+    card_types.insert_card_type(CreditCardType {
+        type_: CardBrand::Custom("zzz"),
+        ..CreditCardType::default()
+    });
+    card_types.insert_card_type(CreditCardType {
+        nice_type: "VISA",
+        ..DEFAULT_CARD_TYPES[11].clone()
+    });
+    card_types.remove_card_type(CardBrand::Elo);
+    card_types.remove_card_type(CardBrand::Custom("missing"));
+
+    let all_brands = card_types
+        .card_types()
+        .iter()
+        .map(|card| card.type_)
+        .collect::<Vec<_>>();
+    assert_eq!(all_brands.first(), Some(&CardBrand::Custom("aaa")));
+    assert_eq!(all_brands.last(), Some(&CardBrand::Custom("zzz")));
+    assert!(!all_brands.contains(&CardBrand::Elo));
+    assert!(all_brands.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let card_type = card_types.get_credit_card_type("4").unwrap();
+    assert_eq!(card_type.len(), 2);
+    assert!(card_type.iter().any(|card| card.nice_type == "VISA"));
+}
+
 #[test]
 fn test_create_default_card_type() {
     let mut card_types = CreditCardPool::new();
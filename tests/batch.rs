@@ -0,0 +1,148 @@
+#![cfg(feature = "std")]
+
+use credit_card_types::{BatchError, BatchFormat, BatchProcessor, BatchSummary, CreditCardPool};
+
+fn process(processor: &BatchProcessor, input: &str) -> (BatchSummary, String) {
+    let mut output = Vec::new();
+    let summary = processor.process(input.as_bytes(), &mut output).unwrap();
+    (summary, String::from_utf8(output).unwrap())
+}
+
+#[test]
+fn test_batch_csv() {
+    let pool = CreditCardPool::new();
+    let processor = BatchProcessor::new(&pool, BatchFormat::Csv, "pan");
+
+    let input = "id,pan,note\r\n\
+                 1,4111-1111-1111-1111,\"multi\nline, \"\"quoted\"\"\"\r\n\
+                 2,4111111111111112,\n\
+                 \n\
+                 3,3782XXXXXXX0005,masked\n\
+                 4,abc,\n\
+                 5\n";
+    let (summary, output) = process(&processor, input);
+
+    assert_eq!(
+        summary,
+        BatchSummary {
+            rows: 5,
+            valid: 1,
+            invalid: 1,
+            masked: 1,
+            errors: 2,
+        }
+    );
+    assert_eq!(
+        output,
+        "id,pan,note,brand,valid,masked,error\n\
+         1,4111-1111-1111-1111,\"multi\nline, \"\"quoted\"\"\",visa,true,************1111,\n\
+         2,4111111111111112,,visa,false,************1112,\n\
+         3,3782XXXXXXX0005,masked,american-express,,***********0005,\n\
+         4,abc,,,,,not a card number\n\
+         5,,,,,,missing card number column\n"
+    );
+}
+
+#[test]
+fn test_batch_csv_delimiter_and_missing_column() {
+    let pool = CreditCardPool::new();
+    let processor = BatchProcessor::new(&pool, BatchFormat::Csv, "pan").with_delimiter(b';');
+
+    let (summary, output) = process(&processor, "pan;amount\n5;1,50\n");
+    assert_eq!(summary.valid + summary.invalid, 1);
+    assert_eq!(
+        output,
+        "pan;amount;brand;valid;masked;error\n5;1,50;elo|maestro|mastercard;false;5;\n"
+    );
+
+    let result = BatchProcessor::new(&pool, BatchFormat::Csv, "card")
+        .process("pan\n4111111111111111\n".as_bytes(), Vec::new());
+    assert!(matches!(result, Err(BatchError::MissingColumn(column)) if column == "card"));
+}
+
+#[test]
+fn test_batch_json_lines() {
+    let pool = CreditCardPool::new();
+    let processor = BatchProcessor::new(&pool, BatchFormat::JsonLines, "pan");
+
+    let input = "{\"id\": 1, \"pan\": \"6011 0009 9013 9424\", \"meta\": {\"pan\": \"x\"}}\n\
+                 {\"pan\":5555555555554444}\n\
+                 {\"pan\":\"411111\\u002a\\u002a\\u002a\\u002a\\u002a\\u002a1111\"}\n\
+                 {\"pan\":null}\n\
+                 not json\n";
+    let (summary, output) = process(&processor, input);
+
+    assert_eq!(
+        summary,
+        BatchSummary {
+            rows: 5,
+            valid: 2,
+            invalid: 0,
+            masked: 1,
+            errors: 2,
+        }
+    );
+    assert_eq!(
+        output.lines().collect::<Vec<_>>(),
+        [
+            "{\"id\": 1, \"pan\": \"6011 0009 9013 9424\", \"meta\": {\"pan\": \"x\"},\"brand\":\"discover\",\"valid\":true,\"masked\":\"************9424\",\"error\":null}",
+            "{\"pan\":5555555555554444,\"brand\":\"mastercard\",\"valid\":true,\"masked\":\"************4444\",\"error\":null}",
+            "{\"pan\":\"411111\\u002a\\u002a\\u002a\\u002a\\u002a\\u002a1111\",\"brand\":\"visa\",\"valid\":null,\"masked\":\"************1111\",\"error\":null}",
+            "{\"pan\":null,\"brand\":null,\"valid\":null,\"masked\":null,\"error\":\"missing card number column\"}",
+            "{\"brand\":null,\"valid\":null,\"masked\":null,\"error\":\"invalid JSON object\"}",
+        ]
+    );
+}
+
+#[test]
+fn test_batch_blank_and_long_lines() {
+    let pool = CreditCardPool::new();
+
+    let processor = BatchProcessor::new(&pool, BatchFormat::Csv, "pan");
+    let input = format!("pan\n{}4111111111111111\n", "\n".repeat(3_000_000));
+    let (summary, output) = process(&processor, &input);
+    assert_eq!(summary.valid, 1);
+    assert_eq!(
+        output,
+        "pan,brand,valid,masked,error\n4111111111111111,visa,true,************1111,\n"
+    );
+
+    let long_line = "4".repeat(100_000);
+    let input = format!("pan\n{}\n4111111111111111\n", long_line);
+    let (summary, output) = process(&processor, &input);
+    assert_eq!((summary.rows, summary.errors, summary.valid), (2, 1, 1));
+    assert!(output.lines().nth(1).unwrap().ends_with(",record too long"));
+
+    let processor = BatchProcessor::new(&pool, BatchFormat::JsonLines, "pan");
+    let input = format!("{}\n{{\"pan\":\"4111111111111111\"}}\n", long_line);
+    let (summary, output) = process(&processor, &input);
+    assert_eq!((summary.rows, summary.errors, summary.valid), (2, 1, 1));
+    assert_eq!(
+        output.lines().next().unwrap(),
+        "{\"brand\":null,\"valid\":null,\"masked\":null,\"error\":\"line too long\"}"
+    );
+}
+
+#[test]
+fn test_batch_fully_masked() {
+    let pool = CreditCardPool::new();
+    let processor = BatchProcessor::new(&pool, BatchFormat::Csv, "pan");
+
+    let (summary, output) = process(&processor, "pan\n************1111\n");
+    assert_eq!(summary.masked, 1);
+    assert_eq!(
+        output,
+        "pan,brand,valid,masked,error\n\
+         ************1111,,,************1111,\n"
+    );
+
+    // Masked last digits are not shown as digits.
+    let (summary, output) = process(&processor, "pan\n411111******XXXX\n4111111111xxxxxx\n");
+    assert_eq!(summary.masked, 2);
+    assert_eq!(
+        output,
+        "pan,brand,valid,masked,error\n\
+         411111******XXXX,visa,,************XXXX,\n\
+         4111111111xxxxxx,visa,,************xxxx,\n"
+    );
+}
@@ -0,0 +1,240 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    CardBrand, CardRange, CardTypeError, Code, CreditCardPool, CreditCardPoolBuilder,
+    CreditCardType, FundingType, Preset, DEFAULT_CARD_TYPES,
+};
+
+type Modify = fn(CreditCardPoolBuilder) -> CreditCardPoolBuilder;
+
+fn brands(pool: &CreditCardPool) -> Vec<CardBrand> {
+    pool.card_types()
+        .iter()
+        .map(|card_type| card_type.type_)
+        .collect()
+}
+
+#[test]
+fn test_starting_points() {
+    assert!(CreditCardPoolBuilder::none()
+        .build()
+        .unwrap()
+        .card_types()
+        .is_empty());
+
+    assert_eq!(
+        CreditCardPoolBuilder::preset(Preset::Default)
+            .build()
+            .unwrap(),
+        CreditCardPool::new()
+    );
+
+    let all = CreditCardPoolBuilder::all().build().unwrap();
+    assert!(all.card_types().len() >= DEFAULT_CARD_TYPES.len());
+
+    assert_eq!(
+        brands(
+            &CreditCardPoolBuilder::preset(Preset::Major)
+                .build()
+                .unwrap()
+        ),
+        [
+            CardBrand::AmericanExpress,
+            CardBrand::Discover,
+            CardBrand::Mastercard,
+            CardBrand::Visa
+        ]
+    );
+}
+
+#[test]
+fn test_presets_are_known_brands() {
+    for preset in [
+        Preset::Default,
+        Preset::Major,
+        Preset::International,
+        Preset::Brazil,
+    ] {
+        let pool = CreditCardPoolBuilder::preset(preset).build().unwrap();
+        assert_eq!(brands(&pool), preset.brands(), "Failed for {:?}", preset);
+    }
+}
+
+#[test]
+fn test_enable_and_disable() {
+    let pool = CreditCardPoolBuilder::none()
+        .enable(CardBrand::Visa)
+        .enable(CardBrand::Elo)
+        .enable(CardBrand::Mastercard)
+        .disable(CardBrand::Mastercard)
+        .disable(CardBrand::Jcb)
+        .build()
+        .unwrap();
+
+    assert_eq!(brands(&pool), [CardBrand::Elo, CardBrand::Visa]);
+
+    let card_types = pool.get_credit_card_type("5555555555554444").unwrap();
+    assert!(card_types.is_empty());
+
+    let error = CreditCardPoolBuilder::none()
+        .enable(CardBrand::Custom("store-card"))
+        .build()
+        .unwrap_err();
+    assert!(matches!(error, CardTypeError::UnknownCardBrand));
+}
+
+#[test]
+fn test_overrides() {
+    let pool = CreditCardPoolBuilder::preset(Preset::International)
+        .lengths(CardBrand::Visa, &[16])
+        .gaps(CardBrand::Visa, &[4, 8])
+        .code(
+            CardBrand::AmericanExpress,
+            Code {
+                name: "CVV",
+                size: 4,
+            },
+        )
+        .modify(CardBrand::Discover, |card_type| {
+            card_type.nice_type = "Discover Global Network";
+        })
+        .build()
+        .unwrap();
+
+    let visa = &pool.get_credit_card_type("4").unwrap()[0];
+    assert_eq!(visa.lengths, [16]);
+    assert_eq!(
+        visa.format_card_number("4111111111111111"),
+        "4111 1111 11111111"
+    );
+    assert!(!pool
+        .validate_card_number("4111111111111111003")
+        .unwrap()
+        .is_valid());
+
+    let amex = &pool.get_credit_card_type("34").unwrap()[0];
+    assert_eq!(amex.code.name, "CVV");
+
+    let discover = &pool.get_credit_card_type("6011").unwrap()[0];
+    assert_eq!(discover.nice_type, "Discover Global Network");
+
+    let error = CreditCardPoolBuilder::preset(Preset::Major)
+        .lengths(CardBrand::Elo, &[16])
+        .build()
+        .unwrap_err();
+    assert!(matches!(error, CardTypeError::UnknownCardBrand));
+}
+
+#[test]
+fn test_custom_card_type() {
+    let pool = CreditCardPoolBuilder::none()
+        .card_type(CreditCardType {
+            type_: CardBrand::Custom("store-card"),
+            ..CreditCardType::default()
+        })
+        .lengths(CardBrand::Custom("store-card"), &[12])
+        .build()
+        .unwrap();
+
+    let card_types = pool.get_credit_card_type("123456789").unwrap();
+    assert_eq!(card_types.len(), 1);
+    assert_eq!(card_types[0].lengths, [12]);
+}
+
+#[test]
+fn test_validation() {
+    let invalid: [(Modify, &str); 7] = [
+        (
+            |builder| builder.lengths(CardBrand::Visa, &[]),
+            "no lengths",
+        ),
+        (
+            |builder| builder.lengths(CardBrand::Visa, &[16, 20]),
+            "length out of range",
+        ),
+        (
+            |builder| builder.patterns(CardBrand::Visa, &[]),
+            "no patterns",
+        ),
+        (
+            |builder| builder.patterns(CardBrand::Visa, &[&["4a"]]),
+            "malformed pattern",
+        ),
+        (
+            |builder| builder.patterns(CardBrand::Visa, &[&["49", "40"]]),
+            "malformed pattern",
+        ),
+        (
+            |builder| builder.patterns(CardBrand::Visa, &[&["40", "499"]]),
+            "malformed pattern",
+        ),
+        (
+            |builder| builder.gaps(CardBrand::Visa, &[8, 4]),
+            "gaps not increasing or outside of the number",
+        ),
+    ];
+
+    for (modify, expected_reason) in invalid {
+        let error = modify(CreditCardPoolBuilder::preset(Preset::Major))
+            .build()
+            .unwrap_err();
+
+        match error {
+            CardTypeError::InvalidCardType(brand, reason) => {
+                assert_eq!(brand, CardBrand::Visa);
+                assert_eq!(reason, expected_reason);
+            }
+            error => panic!("unexpected error {error:?}"),
+        }
+    }
+
+    let error = CreditCardPoolBuilder::none()
+        .lengths(CardBrand::Visa, &[])
+        .build()
+        .unwrap_err();
+    assert_eq!(error.to_string(), "unknown card brand");
+
+    let error = CreditCardPoolBuilder::preset(Preset::Major)
+        .gaps(CardBrand::Visa, &[4, 19])
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid card type `visa`: gaps not increasing or outside of the number"
+    );
+}
+
+#[test]
+fn test_long_range_bounds() {
+    // Bounds longer than an `i32` compare like shorter ones.
+    const RANGES: &[CardRange] =
+        &[CardRange::new(&["4111111111", "4111111112"]).with_funding(FundingType::Prepaid)];
+    let pool = CreditCardPoolBuilder::none()
+        .card_type(CreditCardType {
+            type_: CardBrand::Custom("gift-card"),
+            patterns: &[&["411111111100", "411111111299"]],
+            ranges: RANGES,
+            ..CreditCardType::default()
+        })
+        .build()
+        .unwrap();
+
+    for (card_number, count) in [
+        ("4111111111111111", 1),
+        ("4111111112990000", 1),
+        ("4111111113000000", 0),
+        ("41111111", 1),
+    ] {
+        assert_eq!(
+            pool.get_credit_card_type(card_number).unwrap().len(),
+            count,
+            "Failed for {}",
+            card_number
+        );
+    }
+    assert_eq!(
+        pool.funding_type("4111111111111111").unwrap(),
+        FundingType::Prepaid
+    );
+    assert!(pool.get_credit_card_type("41111111110a").is_err());
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_credit-card-types"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_detect() {
+    let output = run(&["detect", "4111 1111 1111 1111", "3"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "**** **** **** 1111\tvisa\n3\tamerican-express,diners-club,jcb\n"
+    );
+
+    let output = run(&["detect", "--json", "7"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "{\"input\":\"7\",\"brands\":[]}\n");
+}
+
+#[test]
+fn test_validate_from_stdin() {
+    let output = run(&["validate"], "4111111111111111\n\n4111111111111112\n");
+    assert_eq!(output.status.code(), Some(1));
+    let lines = stdout(&output);
+    let lines = lines.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("valid=true"));
+    assert!(lines[1].contains("luhn_valid=false"));
+
+    let output = run(&["validate", "--json"], "378282246310005\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "{\"input\":\"***********0005\",\"valid\":true,\"brand\":\"american-express\",\"luhn_valid\":true,\"length_valid\":true}\n"
+    );
+}
+
+#[test]
+fn test_format_and_mask() {
+    let output = run(&["format", "378282246310005"], "");
+    assert_eq!(stdout(&output), "***********0005\t3782 822463 10005\n");
+
+    // The raw number is never printed next to the masked one.
+    let output = run(&["mask", "4111-1111-1111-1111", "abc"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!stdout(&output).contains("4111-1111-1111-1111"));
+    assert_eq!(stdout(&output), "************1111\nnot a card number\n");
+
+    let output = run(&["mask", "--json", "4111111111111111"], "");
+    assert!(!stdout(&output).contains("4111111111111111"));
+    assert_eq!(stdout(&output), "{\"masked\":\"************1111\"}\n");
+
+    // Unless asked for.
+    let output = run(&["detect", "--show-numbers", "4111111111111111"], "");
+    assert_eq!(stdout(&output), "4111111111111111\tvisa\n");
+    let output = run(&["mask", "--show-numbers", "4111111111111111"], "");
+    assert_eq!(stdout(&output), "4111111111111111\t************1111\n");
+}
+
+#[test]
+fn test_generate() {
+    let output = run(&["generate", "--brand", "mastercard", "--count", "5"], "");
+    assert_eq!(output.status.code(), Some(0));
+    let numbers = stdout(&output)
+        .lines()
+        .map(|line| line.split('\t').next().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(numbers.len(), 5);
+
+    let mut args = vec!["validate"];
+    args.extend(numbers.iter().map(String::as_str));
+    assert_eq!(run(&args, "").status.code(), Some(0));
+
+    let seeded = run(&["generate", "--brand", "jcb", "--seed", "42"], "");
+    assert_eq!(
+        stdout(&seeded),
+        stdout(&run(&["generate", "--brand", "jcb", "--seed", "42"], ""))
+    );
+}
+
+#[test]
+fn test_batch() {
+    let output = run(
+        &["batch", "--column", "pan"],
+        "id,pan\n1,4111111111111111\n2,4111111111111112\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "id,pan,brand,valid,masked,error\n\
+         1,4111111111111111,visa,true,************1111,\n\
+         2,4111111111111112,visa,false,************1112,\n"
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "2 rows: 1 valid, 1 invalid, 0 masked, 0 errors\n"
+    );
+
+    let output = run(
+        &["batch", "--format", "jsonl", "--column", "pan"],
+        "{\"pan\":\"378282246310005\"}\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "{\"pan\":\"378282246310005\",\"brand\":\"american-express\",\"valid\":true,\"masked\":\"***********0005\",\"error\":null}\n"
+    );
+}
+
+#[test]
+fn test_usage_errors() {
+    assert_eq!(run(&["unknown"], "").status.code(), Some(2));
+    assert_eq!(run(&["generate"], "").status.code(), Some(2));
+    assert_eq!(
+        run(&["generate", "--brand", "amex"], "").status.code(),
+        Some(2)
+    );
+    assert_eq!(run(&["detect", "--nope"], "").status.code(), Some(2));
+    assert_eq!(run(&["batch"], "").status.code(), Some(2));
+    assert_eq!(
+        run(&["batch", "--column", "pan"], "card\n").status.code(),
+        Some(2)
+    );
+    assert_eq!(run(&["--help"], "").status.code(), Some(0));
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    CardBrand, CardRange, CreditCardPool, CreditCardType, StaticCardPool, DEFAULT_CARD_TYPES,
+};
+
+#[test]
+fn test_default_co_badged_ranges() {
+    let pool = CreditCardPool::new();
+
+    let card_matches = pool.get_card_matches("4571000000000001").unwrap();
+    assert_eq!(card_matches.len(), 1);
+    assert_eq!(card_matches[0].primary(), CardBrand::Visa);
+    assert!(card_matches[0].is_co_badged());
+    assert_eq!(
+        card_matches[0].networks().collect::<Vec<_>>(),
+        [CardBrand::Visa, CardBrand::Dankort]
+    );
+
+    let card_matches = pool.get_card_matches("6504050000000000").unwrap();
+    assert_eq!(card_matches.len(), 1);
+    assert_eq!(
+        card_matches[0].networks().collect::<Vec<_>>(),
+        [CardBrand::Elo, CardBrand::Discover]
+    );
+
+    let card_matches = pool.get_card_matches("4111111111111111").unwrap();
+    assert_eq!(card_matches.len(), 1);
+    assert!(!card_matches[0].is_co_badged());
+    assert_eq!(
+        card_matches[0].networks().collect::<Vec<_>>(),
+        [CardBrand::Visa]
+    );
+
+    // Elo cards outside of the Discover range are single network.
+    let card_matches = pool.get_card_matches("5067000000000000").unwrap();
+    assert_eq!(card_matches[0].primary(), CardBrand::Elo);
+    assert!(!card_matches[0].is_co_badged());
+}
+
+#[test]
+fn test_partial_numbers_are_not_co_badged() {
+    let pool = CreditCardPool::new();
+
+    let card_matches = pool.get_card_matches("457").unwrap();
+    assert!(card_matches.len() > 1);
+    assert!(card_matches
+        .iter()
+        .all(|card_match| !card_match.is_co_badged()));
+
+    let card_matches = pool.get_card_matches("4571").unwrap();
+    assert_eq!(card_matches[0].co_badged, [CardBrand::Dankort]);
+}
+
+#[test]
+fn test_card_matches_agree_with_card_types() {
+    let pool = CreditCardPool::new();
+
+    for card_number in ["", "4", "3", "6", "6500", "4571000000000001", "0"] {
+        let card_types = pool.get_credit_card_type(card_number).unwrap();
+        let card_matches = pool.get_card_matches(card_number).unwrap();
+
+        assert_eq!(
+            card_matches
+                .into_iter()
+                .map(|card_match| card_match.card_type)
+                .collect::<Vec<_>>(),
+            card_types,
+            "Failed for {}",
+            card_number
+        );
+    }
+
+    assert!(pool.get_card_matches("4571a").is_err());
+}
+
+#[test]
+fn test_most_specific_range_wins() {
+    const RANGES: &[CardRange] = &[
+        CardRange::new(&["5163"]).with_co_badged(&[CardBrand::Eftpos]),
+        CardRange::new(&["516300", "516309"]).with_co_badged(&[CardBrand::CartesBancaires]),
+    ];
+
+    let mastercard = DEFAULT_CARD_TYPES
+        .iter()
+        .find(|card_type| card_type.type_ == CardBrand::Mastercard)
+        .unwrap();
+
+    let mut pool = CreditCardPool::new();
+    pool.insert_card_type(CreditCardType {
+        ranges: RANGES,
+        ..mastercard.clone()
+    });
+
+    let card_match = &pool.get_card_matches("5163050000000000").unwrap()[0];
+    assert_eq!(card_match.co_badged, [CardBrand::CartesBancaires]);
+
+    let card_match = &pool.get_card_matches("5163500000000000").unwrap()[0];
+    assert_eq!(card_match.co_badged, [CardBrand::Eftpos]);
+
+    let card_match = &pool.get_card_matches("5164000000000000").unwrap()[0];
+    assert!(card_match.co_badged.is_empty());
+}
+
+#[test]
+fn test_static_pool_card_matches() {
+    let pool = StaticCardPool::new();
+
+    let mut card_matches = pool.get_card_matches("4571000000000001").unwrap();
+    let card_match = card_matches.next().unwrap();
+    assert_eq!(card_match.primary(), CardBrand::Visa);
+    assert_eq!(card_match.co_badged, [CardBrand::Dankort]);
+    assert!(card_matches.next().is_none());
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{CardBrand, CardTypeError, CreditCardPool, IncrementalDetector};
+
+#[test]
+fn test_same_card_types_as_the_pool() {
+    let pool = CreditCardPool::new();
+    let card_numbers = [
+        "4111111111111111",
+        "378282246310005",
+        "6011111111111117",
+        "3530111333300000",
+        "6362970000457013",
+        "6504050000000000",
+        "2200000000000004",
+        "7000000000000000",
+    ];
+
+    for card_number in card_numbers {
+        let mut detector = IncrementalDetector::new(&pool);
+        assert_eq!(detector.card_types(), pool.get_all_card_types());
+
+        for (index, digit) in card_number.char_indices() {
+            detector.push(digit).unwrap();
+            assert_eq!(
+                detector.card_types(),
+                pool.get_credit_card_type(&card_number[..=index]).unwrap(),
+                "{}",
+                &card_number[..=index]
+            );
+        }
+
+        for index in (0..card_number.len()).rev() {
+            assert_eq!(detector.backspace(), card_number[index..].chars().next());
+            assert_eq!(
+                detector.card_types(),
+                pool.get_credit_card_type(&card_number[..index]).unwrap()
+            );
+        }
+        assert_eq!(detector.backspace(), None);
+    }
+}
+
+#[test]
+fn test_remaining_digits_and_max_length() {
+    let pool = CreditCardPool::new();
+    let mut detector = IncrementalDetector::new(&pool);
+
+    detector.push('3').unwrap();
+    // JCB starts with 3528 to 3589.
+    assert_eq!(detector.remaining_digits(), 3);
+    assert_eq!(detector.max_length(), Some(19));
+
+    detector.push('4').unwrap();
+    assert_eq!(detector.brand(), Some(CardBrand::AmericanExpress));
+    assert_eq!(detector.remaining_digits(), 0);
+    assert_eq!(detector.max_length(), Some(15));
+
+    detector.clear();
+    assert_eq!(detector.card_number(), "");
+    assert_eq!(detector.card_types(), pool.get_all_card_types());
+}
+
+#[test]
+fn test_can_become_valid() {
+    let pool = CreditCardPool::new();
+    let mut detector = IncrementalDetector::new(&pool);
+
+    detector.push_str("378282246310005").unwrap();
+    assert!(detector.can_become_valid());
+
+    detector.push('1').unwrap();
+    assert!(!detector.can_become_valid());
+    detector.backspace();
+    assert!(detector.can_become_valid());
+
+    // At the largest length, the number has to pass the Luhn checksum.
+    detector.backspace();
+    detector.push('6').unwrap();
+    assert_eq!(detector.card_number(), "378282246310006");
+    assert!(!detector.can_become_valid());
+
+    // Visa numbers can be 16, 18 or 19 digits long.
+    detector.clear();
+    detector.push_str("41111111111111112").unwrap();
+    assert!(detector.can_become_valid());
+    detector.clear();
+    detector.push_str("4111111111111111113").unwrap();
+    assert_eq!(detector.max_length(), Some(19));
+    assert!(!detector.can_become_valid());
+    detector.backspace();
+    detector.push('0').unwrap();
+    assert!(detector.can_become_valid());
+
+    detector.clear();
+    detector.push('7').unwrap();
+    assert!(detector.brand().is_none());
+    assert!(!detector.can_become_valid());
+    assert_eq!(detector.max_length(), None);
+}
+
+#[test]
+fn test_invalid_digits() {
+    let pool = CreditCardPool::new();
+    let mut detector = IncrementalDetector::new(&pool);
+
+    assert!(matches!(
+        detector.push_str("41a1"),
+        Err(CardTypeError::InvalidCardNumber)
+    ));
+    assert_eq!(detector.card_number(), "41");
+    assert_eq!(detector.brand(), Some(CardBrand::Visa));
+}
@@ -0,0 +1,210 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    regions, CardBrand, Change, Code, CreditCardPool, CreditCardPoolBuilder, CreditCardType,
+    Preset, Reclassification, DEFAULT_CARD_TYPES,
+};
+
+fn card_type(brand: CardBrand) -> CreditCardType {
+    DEFAULT_CARD_TYPES
+        .iter()
+        .find(|card_type| card_type.type_ == brand)
+        .unwrap()
+        .clone()
+}
+
+fn reclassification(prefix: &str, old: &[CardBrand], new: &[CardBrand]) -> Reclassification {
+    Reclassification {
+        prefix: prefix.to_string(),
+        old: old.to_vec(),
+        new: new.to_vec(),
+    }
+}
+
+#[test]
+fn test_data_version() {
+    let pool = CreditCardPool::new();
+    let version = pool.data_version();
+
+    assert_eq!(version, CreditCardPool::default().data_version());
+    assert_eq!(
+        version,
+        CreditCardPoolBuilder::preset(Preset::Default)
+            .build()
+            .unwrap()
+            .data_version()
+    );
+    assert_eq!(version.to_string(), format!("{:016x}", version.0));
+
+    // Every field is part of the version.
+    let changes: [fn(&mut CreditCardType); 7] = [
+        |card_type| card_type.nice_type = "VISA",
+        |card_type| card_type.patterns = &[&["4"], &["1"]],
+        |card_type| card_type.ranges = &[],
+        |card_type| card_type.gaps = &[4, 8],
+        |card_type| card_type.lengths = &[16],
+        |card_type| card_type.code.size = 4,
+        |card_type| card_type.match_strength = 1,
+    ];
+    for change in changes {
+        let mut visa = card_type(CardBrand::Visa);
+        change(&mut visa);
+        let mut changed = pool.clone();
+        changed.insert_card_type(visa);
+
+        assert_ne!(changed.data_version(), version);
+    }
+
+    let mut mastercard = card_type(CardBrand::Mastercard);
+    mastercard.validity = &[];
+    let mut changed = pool.clone();
+    changed.insert_card_type(mastercard);
+    assert_ne!(changed.data_version(), version);
+
+    let mut changed = pool.clone();
+    changed.extend(regions::ENABLED.iter().cloned());
+    assert_eq!(
+        changed.data_version() == version,
+        regions::ENABLED.is_empty()
+    );
+
+    assert_ne!(CreditCardPool::new_empty().data_version(), version);
+}
+
+#[test]
+fn test_diff() {
+    let old = CreditCardPool::new();
+    assert!(old.diff(&old.clone()).is_empty());
+
+    let mut new = old.clone();
+    new.remove_card_type(CardBrand::Hiper);
+    new.insert_card_type(CreditCardType {
+        nice_type: "Private label",
+        type_: CardBrand::Custom("private-label"),
+        patterns: &[&["1"]],
+        ..card_type(CardBrand::Visa)
+    });
+    new.insert_card_type(CreditCardType {
+        patterns: &[&["34"]],
+        gaps: &[4, 8, 12],
+        code: Code {
+            name: "CVV",
+            size: 4,
+        },
+        ..card_type(CardBrand::AmericanExpress)
+    });
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.added, [CardBrand::Custom("private-label")]);
+    assert_eq!(diff.removed, [CardBrand::Hiper]);
+    assert_eq!(diff.changed.len(), 1);
+
+    let change = &diff.changed[0];
+    assert_eq!(change.brand, CardBrand::AmericanExpress);
+    assert_eq!(
+        change.patterns,
+        Some(Change {
+            old: &[&["34"][..], &["37"]][..],
+            new: &[&["34"][..]][..],
+        })
+    );
+    assert_eq!(
+        change.gaps,
+        Some(Change {
+            old: &[4, 10][..],
+            new: &[4, 8, 12][..],
+        })
+    );
+    assert_eq!(change.code.as_ref().unwrap().old.name, "CID");
+    assert_eq!(change.code.as_ref().unwrap().new.size, 4);
+    assert_eq!(change.nice_type, None);
+    assert_eq!(change.lengths, None);
+    assert_eq!(change.ranges, None);
+    assert_eq!(change.validity, None);
+
+    let reverse = new.diff(&old);
+    assert_eq!(reverse.added, diff.removed);
+    assert_eq!(reverse.removed, diff.added);
+}
+
+#[test]
+fn test_reclassified_prefixes() {
+    let old = CreditCardPool::new();
+    assert!(old.reclassified_prefixes(&old.clone()).unwrap().is_empty());
+
+    // Changes that don't affect detection.
+    let new = CreditCardPoolBuilder::preset(Preset::Default)
+        .gaps(CardBrand::Visa, &[4, 8])
+        .lengths(CardBrand::Maestro, &[16])
+        .build()
+        .unwrap();
+    assert!(!old.diff(&new).is_empty());
+    assert!(old.reclassified_prefixes(&new).unwrap().is_empty());
+
+    // A removed brand, the prefixes of its range are merged.
+    let mut new = old.clone();
+    new.remove_card_type(CardBrand::Mir);
+    assert_eq!(
+        old.reclassified_prefixes(&new).unwrap(),
+        ["2200", "2201", "2202", "2203", "2204"].map(|prefix| reclassification(
+            prefix,
+            &[CardBrand::Mir],
+            &[]
+        ))
+    );
+
+    // A narrowed range.
+    let mut new = old.clone();
+    new.insert_card_type(CreditCardType {
+        patterns: &[&["34"]],
+        ..card_type(CardBrand::AmericanExpress)
+    });
+    assert_eq!(
+        old.reclassified_prefixes(&new).unwrap(),
+        [reclassification("37", &[CardBrand::AmericanExpress], &[])]
+    );
+
+    // A more specific range taken by another brand.
+    let mut new = old.clone();
+    new.insert_card_type(CreditCardType {
+        nice_type: "Fleet",
+        type_: CardBrand::Custom("fleet"),
+        patterns: &[&["456700", "456799"]],
+        ..card_type(CardBrand::Visa)
+    });
+    assert_eq!(
+        old.reclassified_prefixes(&new).unwrap(),
+        [reclassification(
+            "4567",
+            &[CardBrand::Visa],
+            &[CardBrand::Custom("fleet")]
+        )]
+    );
+
+    // An upgrade adding the regional networks.
+    let mut new = old.clone();
+    new.extend(regions::ENABLED.iter().cloned());
+    let reclassifications = old.reclassified_prefixes(&new).unwrap();
+    assert_eq!(reclassifications.is_empty(), regions::ENABLED.is_empty());
+    for reclassification in reclassifications {
+        assert_ne!(reclassification.old, reclassification.new);
+        let number = format!("{:0<16}", reclassification.prefix);
+        assert_eq!(
+            new.get_credit_card_type(&number)
+                .unwrap()
+                .iter()
+                .map(|card_type| card_type.type_)
+                .collect::<Vec<_>>(),
+            reclassification.new,
+            "Failed for {}",
+            number
+        );
+    }
+
+    let mut malformed = old.clone();
+    malformed.insert_card_type(CreditCardType {
+        patterns: &[&["4a", "4b"]],
+        ..card_type(CardBrand::Visa)
+    });
+    assert!(old.reclassified_prefixes(&malformed).is_err());
+}
@@ -0,0 +1,214 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    CardBrand, CardTypeError, CreditCardPool, CreditCardPoolBuilder, CreditCardType, Date,
+    PatternValidity, Preset, StaticCardPool,
+};
+
+fn date(date: &str) -> Date {
+    date.parse().unwrap()
+}
+
+const fn const_date(year: u16, month: u8, day: u8) -> Date {
+    match Date::new(year, month, day) {
+        Ok(date) => date,
+        Err(_) => panic!("invalid date"),
+    }
+}
+
+fn brands(card_types: &[CreditCardType]) -> Vec<CardBrand> {
+    card_types.iter().map(|card_type| card_type.type_).collect()
+}
+
+#[test]
+fn test_dates() {
+    let leap_day = date("2024-02-29");
+    assert_eq!(leap_day, Date::new(2024, 2, 29).unwrap());
+    assert_eq!(
+        (leap_day.year(), leap_day.month(), leap_day.day()),
+        (2024, 2, 29)
+    );
+    assert_eq!(date("2000-02-29").to_string(), "2000-02-29");
+    assert!(date("2016-12-31") < date("2017-01-01"));
+    assert!(date("2017-01-31") < date("2017-02-01"));
+
+    for input in [
+        "2023-02-29",
+        "1900-02-29",
+        "2017-13-01",
+        "2017-00-01",
+        "2017-04-31",
+        "2017-01-00",
+        "2017-1-01",
+        "2017/01/01",
+        "20170101",
+        "2017-01-01 ",
+        "+017-01-01",
+        "2017é01-01",
+        "",
+    ] {
+        assert!(
+            matches!(input.parse::<Date>(), Err(CardTypeError::InvalidDate)),
+            "Failed for {}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_mastercard_2_series() {
+    let pool = CreditCardPool::new();
+
+    let numbers = ["2221000000000009", "2300000000000003", "2720990000000007"];
+    for number in numbers {
+        assert!(
+            pool.get_credit_card_type_on(number, date("2016-12-31"))
+                .unwrap()
+                .is_empty(),
+            "Failed for {}",
+            number
+        );
+        for as_of in ["2017-01-01", "2024-06-01"] {
+            assert_eq!(
+                brands(&pool.get_credit_card_type_on(number, date(as_of)).unwrap()),
+                [CardBrand::Mastercard],
+                "Failed for {} on {}",
+                number,
+                as_of
+            );
+        }
+        // Detection without a date uses every pattern.
+        assert_eq!(
+            brands(&pool.get_credit_card_type(number).unwrap()),
+            [CardBrand::Mastercard]
+        );
+    }
+
+    // The 5-series has no dates.
+    assert_eq!(
+        brands(
+            &pool
+                .get_credit_card_type_on("5555555555554444", date("1990-01-01"))
+                .unwrap()
+        ),
+        [CardBrand::Mastercard]
+    );
+
+    // Before 2017, a number starting with 2 can only be JCB or Mir.
+    assert_eq!(
+        brands(
+            &pool
+                .get_credit_card_type_on("2", date("2016-06-01"))
+                .unwrap()
+        ),
+        [CardBrand::Jcb, CardBrand::Mir]
+    );
+    assert_eq!(
+        brands(
+            &pool
+                .get_credit_card_type_on("2", date("2017-06-01"))
+                .unwrap()
+        ),
+        brands(&pool.get_credit_card_type("2").unwrap())
+    );
+
+    let static_pool = StaticCardPool::new();
+    assert_eq!(
+        static_pool
+            .get_credit_card_type_on("2221000000000009", date("2016-12-31"))
+            .unwrap()
+            .count(),
+        0
+    );
+    assert!(pool
+        .get_credit_card_type_on("2221a", date("2017-01-01"))
+        .is_err());
+}
+
+#[test]
+fn test_validity_in_builder() {
+    static RETIRED: &[PatternValidity] = &[PatternValidity::new(&["51", "55"])
+        .with_valid_from(const_date(1990, 1, 1))
+        .with_valid_until(const_date(2030, 12, 31))];
+    let pool = CreditCardPoolBuilder::preset(Preset::Major)
+        .validity(CardBrand::Mastercard, RETIRED)
+        .build()
+        .unwrap();
+    assert!(pool
+        .get_credit_card_type_on("5555555555554444", date("2031-01-01"))
+        .unwrap()
+        .is_empty());
+    // The 2-series has no dates anymore.
+    assert_eq!(
+        brands(
+            &pool
+                .get_credit_card_type_on("2221000000000009", date("2010-01-01"))
+                .unwrap()
+        ),
+        [CardBrand::Mastercard]
+    );
+
+    // New patterns drop the dates of the previous ones.
+    let pool = CreditCardPoolBuilder::preset(Preset::Major)
+        .patterns(CardBrand::Mastercard, &[&["51", "55"]])
+        .build()
+        .unwrap();
+    let mastercard = pool
+        .card_types()
+        .iter()
+        .find(|card_type| card_type.type_ == CardBrand::Mastercard)
+        .unwrap();
+    assert!(mastercard.validity.is_empty());
+
+    static UNKNOWN_PATTERN: &[PatternValidity] = &[PatternValidity::new(&["56"])];
+    static BACKWARDS: &[PatternValidity] = &[PatternValidity::new(&["51", "55"])
+        .with_valid_from(const_date(2020, 1, 1))
+        .with_valid_until(const_date(2019, 1, 1))];
+    for (validity, reason) in [
+        (UNKNOWN_PATTERN, "validity of an unknown pattern"),
+        (BACKWARDS, "validity ending before it starts"),
+    ] {
+        let result = CreditCardPoolBuilder::preset(Preset::Major)
+            .validity(CardBrand::Mastercard, validity)
+            .build();
+        assert!(
+            matches!(
+                result,
+                Err(CardTypeError::InvalidCardType(CardBrand::Mastercard, error)) if error == reason
+            ),
+            "Failed for {}",
+            reason
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid card type `store-card`: validity of an unknown pattern")]
+fn test_insert_validity_of_unknown_pattern() {
+    static MISTYPED: &[PatternValidity] =
+        &[PatternValidity::new(&["6500", "6590"]).with_valid_until(const_date(2019, 12, 31))];
+
+    let mut pool = CreditCardPool::new_empty();
+    pool.insert_card_type(CreditCardType {
+        type_: CardBrand::Custom("store-card"),
+        patterns: &[&["6500", "6509"]],
+        validity: MISTYPED,
+        ..CreditCardType::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "invalid card type `store-card`: validity ending before it starts")]
+fn test_extend_validity_ending_before_it_starts() {
+    static BACKWARDS: &[PatternValidity] = &[PatternValidity::new(&["6500", "6509"])
+        .with_valid_from(const_date(2020, 1, 1))
+        .with_valid_until(const_date(2019, 12, 31))];
+
+    let mut pool = CreditCardPool::new_empty();
+    pool.extend([CreditCardType {
+        type_: CardBrand::Custom("store-card"),
+        patterns: &[&["6500", "6509"]],
+        validity: BACKWARDS,
+        ..CreditCardType::default()
+    }]);
+}
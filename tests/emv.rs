@@ -0,0 +1,169 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{BrandCheck, CardBrand, CardTypeError, CreditCardPool, EmvData, Tlv};
+
+/// Encodes a data object with a short or long length.
+fn tlv(tag: &[u8], value: &[u8]) -> Vec<u8> {
+    let mut data = tag.to_vec();
+    if value.len() < 0x80 {
+        data.push(value.len() as u8);
+    } else {
+        data.extend([0x81, value.len() as u8]);
+    }
+    data.extend(value);
+    data
+}
+
+#[test]
+fn test_tlv_iter() {
+    let long_value = [0x11; 200];
+    let mut data = tlv(&[0x5A], &[0x41, 0x11]);
+    data.extend([0x00, 0xFF]);
+    data.extend(tlv(&[0x9F, 0x06], &[0xA0, 0x00]));
+    data.extend(tlv(&[0x70], &tlv(&[0x57], &[0x41])));
+    data.extend(tlv(&[0xDF, 0x81, 0x01], &long_value));
+
+    let tlvs = Tlv::iter(&data).collect::<Result<Vec<_>, _>>().unwrap();
+    let tags = tlvs.iter().map(|tlv| tlv.tag).collect::<Vec<_>>();
+    assert_eq!(tags, [0x5A, 0x9F06, 0x70, 0xDF8101]);
+    assert_eq!(tlvs[0].value, [0x41, 0x11]);
+    assert_eq!(tlvs[3].value, long_value);
+    assert_eq!(
+        tlvs.iter().map(Tlv::is_constructed).collect::<Vec<_>>(),
+        [false, false, true, false]
+    );
+
+    let invalid = [
+        &[0x5A, 0x08, 0x41][..],
+        &[0x9F],
+        &[0x5A],
+        &[0x5A, 0x80],
+        &[0x5A, 0x85, 0, 0, 0, 0, 1],
+        &[0x9F, 0x81, 0x81, 0x81, 0x01, 0x00],
+    ];
+    for data in invalid {
+        let mut tlvs = Tlv::iter(data);
+        assert!(
+            matches!(tlvs.next(), Some(Err(CardTypeError::InvalidEmvData(_)))),
+            "Failed for {:02X?}",
+            data
+        );
+        assert!(tlvs.next().is_none());
+    }
+}
+
+#[test]
+fn test_emv_data() {
+    // A response template with the Track 2 equivalent data and the AID of the card.
+    let mut template = tlv(&[0x4F], &[0xA0, 0x00, 0x00, 0x00, 0x04, 0x10, 0x10]);
+    template.extend(tlv(
+        &[0x57],
+        &[
+            0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x44, 0x44, 0xD2, 0x51, 0x22, 0x01, 0x12, 0x3F,
+        ],
+    ));
+    template.extend(tlv(&[0x5F, 0x24], &[0x25, 0x12, 0x31]));
+    let data = tlv(&[0x77], &template);
+
+    let emv_data = EmvData::parse(&data).unwrap();
+    assert_eq!(emv_data.pan, None);
+    assert_eq!(emv_data.pan().unwrap().expose(), "5555555555554444");
+    let track2 = emv_data.track2.as_ref().unwrap();
+    assert_eq!(track2.service_code, "201".parse().ok());
+    assert_eq!(track2.discretionary_data, "123");
+    assert_eq!(emv_data.expiry.unwrap().to_string(), "12/25");
+    assert_eq!(
+        emv_data.aid.as_deref(),
+        Some(&[0xA0, 0x00, 0x00, 0x00, 0x04, 0x10, 0x10][..])
+    );
+    assert_eq!(emv_data.aid_network(), Some(CardBrand::Mastercard));
+
+    // The AID of the terminal is preferred over the one of the card.
+    let mut data = data;
+    data.extend(tlv(
+        &[0x9F, 0x06],
+        &[0xA0, 0x00, 0x00, 0x00, 0x04, 0x30, 0x60],
+    ));
+    let emv_data = EmvData::parse(&data).unwrap();
+    assert_eq!(emv_data.aid_network(), Some(CardBrand::Maestro));
+
+    let invalid = [
+        tlv(&[0x5A], &[0x41, 0x1A]),
+        tlv(&[0x5A], &[0x41, 0xF1]),
+        tlv(&[0x5F, 0x24], &[0x25, 0x13, 0x31]),
+        tlv(&[0x5F, 0x24], &[0x25, 0x12]),
+    ];
+    for data in invalid {
+        assert!(
+            matches!(EmvData::parse(&data), Err(CardTypeError::InvalidEmvData(_))),
+            "Failed for {:02X?}",
+            data
+        );
+    }
+    assert!(matches!(
+        EmvData::parse(&tlv(&[0x57], &[0x41, 0x11])),
+        Err(CardTypeError::InvalidTrackData(_))
+    ));
+}
+
+#[test]
+fn test_emv_data_nesting() {
+    // Templates nested a few levels deep are decoded.
+    let mut data = tlv(&[0x5A], &[0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11]);
+    for _ in 0..4 {
+        data = tlv(&[0x70], &data);
+    }
+    let emv_data = EmvData::parse(&data).unwrap();
+    assert_eq!(emv_data.pan().unwrap().expose(), "4111111111111111");
+
+    // Deeply nested templates are rejected without exhausting the stack.
+    let depth = 200_000;
+    let mut data = Vec::with_capacity(depth * 5);
+    for level in 0..depth {
+        let length = (depth - 1 - level) * 5;
+        data.push(0x70);
+        data.push(0x83);
+        data.extend(&u32::try_from(length).unwrap().to_be_bytes()[1..]);
+    }
+    assert!(matches!(
+        EmvData::parse(&data),
+        Err(CardTypeError::InvalidEmvData("nesting too deep"))
+    ));
+}
+
+#[test]
+fn test_check_brand() {
+    let pool = CreditCardPool::new();
+    let visa_pan = tlv(&[0x5A], &[0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11]);
+    let dankort_pan = tlv(&[0x5A], &[0x45, 0x71, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]);
+    let aid = |rid: [u8; 5]| tlv(&[0x9F, 0x06], &[rid.as_slice(), &[0x10, 0x10]].concat());
+
+    let check = |parts: &[&[u8]]| EmvData::parse(&parts.concat()).unwrap().check_brand(&pool);
+
+    assert_eq!(
+        check(&[&visa_pan, &aid([0xA0, 0, 0, 0, 0x03])]).unwrap(),
+        BrandCheck::Consistent(CardBrand::Visa)
+    );
+    assert_eq!(
+        check(&[&visa_pan, &aid([0xA0, 0, 0, 0, 0x04])]).unwrap(),
+        BrandCheck::Mismatch {
+            detected: CardBrand::Visa,
+            aid_network: CardBrand::Mastercard,
+        }
+    );
+    // Visa/Dankort cards can run on either network.
+    assert_eq!(
+        check(&[&dankort_pan, &aid([0xA0, 0, 0, 0x01, 0x21])]).unwrap(),
+        BrandCheck::Consistent(CardBrand::Dankort)
+    );
+
+    assert_eq!(check(&[&visa_pan]).unwrap(), BrandCheck::Unknown);
+    assert_eq!(
+        check(&[&aid([0xA0, 0, 0, 0, 0x03])]).unwrap(),
+        BrandCheck::Unknown
+    );
+    assert_eq!(
+        check(&[&visa_pan, &aid([0xA0, 0, 0, 0x09, 0x99])]).unwrap(),
+        BrandCheck::Unknown
+    );
+}
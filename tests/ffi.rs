@@ -0,0 +1,60 @@
+#![cfg(feature = "ffi")]
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Builds the static library and returns its path. It is not one of the crate types of the package, because it does
+/// not build for `no_std` targets.
+fn build_static_library(root: &Path) -> PathBuf {
+    let status = Command::new(env!("CARGO"))
+        .args([
+            "rustc",
+            "--lib",
+            "--features",
+            "ffi",
+            "--crate-type",
+            "staticlib",
+        ])
+        .arg("--manifest-path")
+        .arg(root.join("Cargo.toml"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "the static library did not build");
+
+    // The test binary is in `target/debug/deps`, the library in `target/debug`.
+    let test_binary = env::current_exe().unwrap();
+    test_binary
+        .parent()
+        .and_then(Path::parent)
+        .unwrap()
+        .join("libcredit_card_types.a")
+}
+
+#[test]
+fn test_c_harness() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library = build_static_library(root);
+
+    let harness = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi_harness");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(root.join("include"))
+        .arg(root.join("tests/ffi/harness.c"))
+        .arg(&library)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&harness)
+        .status()
+        .unwrap();
+    assert!(status.success(), "the C harness did not compile");
+
+    let output = Command::new(&harness).output().unwrap();
+    assert!(
+        output.status.success(),
+        "the C harness failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,242 @@
+/* Exercises the C API, built and run by tests/ffi.rs. Prints the failed check and exits with 1 on failure. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "credit_card_types.h"
+
+#define CHECK(condition)                                                       \
+    do {                                                                       \
+        if (!(condition)) {                                                    \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,   \
+                    #condition);                                               \
+            return 1;                                                          \
+        }                                                                      \
+    } while (0)
+
+static int test_detect(void) {
+    CctPool *pool = cct_pool_new();
+    CctCardType matches[4];
+    size_t count = 0;
+
+    CHECK(pool != NULL);
+    CHECK(cct_pool_detect(pool, "4111111111111111", matches, 4, &count) == CCT_OK);
+    CHECK(count == 1);
+    CHECK(strcmp(matches[0].brand, "visa") == 0);
+    CHECK(strcmp(matches[0].nice_type, "Visa") == 0);
+    CHECK(strcmp(matches[0].code_name, "CVV") == 0);
+    CHECK(matches[0].code_size == 3);
+    CHECK(matches[0].gaps_len == 3);
+    CHECK(matches[0].gaps[0] == 4 && matches[0].gaps[1] == 8 && matches[0].gaps[2] == 12);
+    CHECK(matches[0].lengths_len == 3);
+    CHECK(matches[0].lengths[0] == 16);
+
+    CHECK(cct_pool_detect(pool, "378282246310005", matches, 4, &count) == CCT_OK);
+    CHECK(count == 1);
+    CHECK(strcmp(matches[0].brand, "american-express") == 0);
+    CHECK(strcmp(matches[0].code_name, "CID") == 0);
+    CHECK(matches[0].code_size == 4);
+
+    CHECK(cct_pool_detect(pool, "0000", matches, 4, &count) == CCT_OK);
+    CHECK(count == 0);
+
+    cct_pool_free(pool);
+    return 0;
+}
+
+static int test_buffer_too_small(void) {
+    CctPool *pool = cct_pool_new();
+    CctCardType match;
+    size_t count = 0;
+
+    /* An empty number matches every card type. */
+    CHECK(cct_pool_detect(pool, "", NULL, 0, &count) == CCT_ERROR_BUFFER_TOO_SMALL);
+    CHECK(count > 1);
+
+    CHECK(cct_pool_detect(pool, "", &match, 1, &count) == CCT_ERROR_BUFFER_TOO_SMALL);
+    CHECK(count > 1);
+    CHECK(match.brand[0] != '\0');
+
+    cct_pool_free(pool);
+    return 0;
+}
+
+static int test_detect_alloc(void) {
+    CctPool *pool = cct_pool_new();
+    CctCardTypeList list;
+
+    CHECK(cct_pool_detect_alloc(pool, "", &list) == CCT_OK);
+    CHECK(list.len > 1);
+    CHECK(list.items != NULL);
+
+    cct_card_type_list_free(&list);
+    CHECK(list.items == NULL);
+    CHECK(list.len == 0);
+
+    /* Freeing twice is harmless, the list is reset. */
+    cct_card_type_list_free(&list);
+
+    cct_pool_free(pool);
+    return 0;
+}
+
+static int test_errors(void) {
+    CctPool *pool = cct_pool_new();
+    CctCardType match;
+    size_t count = 42;
+
+    CHECK(cct_pool_detect(pool, "4111-1111", &match, 1, &count) == CCT_ERROR_INVALID_CARD_NUMBER);
+    CHECK(count == 0);
+    CHECK(cct_pool_detect(pool, "\xff", &match, 1, &count) == CCT_ERROR_INVALID_UTF8);
+    CHECK(cct_pool_detect(NULL, "4111", &match, 1, &count) == CCT_ERROR_NULL_POINTER);
+    CHECK(cct_pool_detect(pool, NULL, &match, 1, &count) == CCT_ERROR_NULL_POINTER);
+    CHECK(cct_pool_detect(pool, "4111", NULL, 1, &count) == CCT_ERROR_NULL_POINTER);
+    CHECK(cct_pool_detect(pool, "4111", &match, 1, NULL) == CCT_ERROR_NULL_POINTER);
+
+    CHECK(strcmp(cct_status_message(CCT_ERROR_BUFFER_TOO_SMALL), "buffer too small") == 0);
+    CHECK(strcmp(cct_status_message(1234), "unknown status") == 0);
+
+    cct_pool_free(pool);
+    cct_pool_free(NULL);
+    return 0;
+}
+
+static int test_custom_card_type(void) {
+    CctPool *pool = cct_pool_new_empty();
+    CctCardType matches[2];
+    size_t count = 0;
+
+    CctPattern patterns[] = {{"1234", NULL}, {"5000", "5099"}};
+    uint32_t gaps[] = {4, 8, 12};
+    uint32_t lengths[] = {16};
+    CctCardTypeSpec spec = {
+        .brand = "loyalty",
+        .nice_type = "Loyalty Card",
+        .patterns = patterns,
+        .patterns_len = 2,
+        .gaps = gaps,
+        .gaps_len = 3,
+        .lengths = lengths,
+        .lengths_len = 1,
+        .code_name = "PIN",
+        .code_size = 4,
+    };
+
+    CHECK(cct_pool_detect(pool, "1234", matches, 2, &count) == CCT_OK);
+    CHECK(count == 0);
+
+    CHECK(cct_pool_insert_card_type(pool, &spec) == CCT_OK);
+
+    CHECK(cct_pool_detect(pool, "1234567890123456", matches, 2, &count) == CCT_OK);
+    CHECK(count == 1);
+    CHECK(strcmp(matches[0].brand, "loyalty") == 0);
+    CHECK(strcmp(matches[0].nice_type, "Loyalty Card") == 0);
+    CHECK(strcmp(matches[0].code_name, "PIN") == 0);
+    CHECK(matches[0].match_strength == 4);
+
+    CHECK(cct_pool_detect(pool, "5055", matches, 2, &count) == CCT_OK);
+    CHECK(count == 1);
+
+    /* Replacing the card type frees the data of the previous one. */
+    for (int i = 0; i < 3; i++) {
+        spec.nice_type = i % 2 == 0 ? "Loyalty" : "Loyalty Card";
+        CHECK(cct_pool_insert_card_type(pool, &spec) == CCT_OK);
+        CHECK(cct_pool_detect(pool, "1234567890123456", matches, 2, &count) == CCT_OK);
+        CHECK(count == 1);
+        CHECK(strcmp(matches[0].brand, "loyalty") == 0);
+        CHECK(strcmp(matches[0].nice_type, spec.nice_type) == 0);
+    }
+
+    CHECK(cct_pool_remove_card_type(pool, "loyalty") == CCT_OK);
+    CHECK(cct_pool_detect(pool, "1234", matches, 2, &count) == CCT_OK);
+    CHECK(count == 0);
+
+    /* A range with bounds of different lengths is rejected. */
+    patterns[1].max = "51";
+    CHECK(cct_pool_insert_card_type(pool, &spec) == CCT_ERROR_INVALID_CARD_TYPE);
+
+    spec.lengths_len = 0;
+    patterns[1].max = "5099";
+    CHECK(cct_pool_insert_card_type(pool, &spec) == CCT_ERROR_INVALID_CARD_TYPE);
+
+    cct_pool_free(pool);
+    return 0;
+}
+
+static int test_long_range_bounds(void) {
+    CctPool *pool = cct_pool_new();
+    CctCardType matches[2];
+    size_t count = 0;
+
+    CctPattern patterns[] = {{"9012345678", "9012345699"}};
+    uint32_t lengths[] = {16};
+    CctCardTypeSpec spec = {
+        .brand = "fleet",
+        .nice_type = "Fleet Card",
+        .patterns = patterns,
+        .patterns_len = 1,
+        .gaps = NULL,
+        .gaps_len = 0,
+        .lengths = lengths,
+        .lengths_len = 1,
+        .code_name = "CVV",
+        .code_size = 3,
+    };
+
+    /* Bounds longer than 9 digits don't break the detection of other numbers. */
+    CHECK(cct_pool_insert_card_type(pool, &spec) == CCT_OK);
+    CHECK(cct_pool_detect(pool, "9012345680000000", matches, 2, &count) == CCT_OK);
+    CHECK(count == 1);
+    CHECK(strcmp(matches[0].brand, "fleet") == 0);
+    CHECK(cct_pool_detect(pool, "9012345700000000", matches, 2, &count) == CCT_OK);
+    CHECK(count == 0);
+    CHECK(cct_pool_detect(pool, "4111111111111111", matches, 2, &count) == CCT_OK);
+    CHECK(count == 1);
+
+    cct_pool_free(pool);
+    return 0;
+}
+
+static int test_replace_built_in_card_type(void) {
+    CctPool *pool = cct_pool_new();
+    CctCardType matches[2];
+    size_t count = 0;
+
+    CctPattern patterns[] = {{"4", NULL}};
+    uint32_t lengths[] = {13, 16, 19};
+    CctCardTypeSpec spec = {
+        .brand = "visa",
+        .nice_type = "Visa Custom",
+        .patterns = patterns,
+        .patterns_len = 1,
+        .gaps = NULL,
+        .gaps_len = 0,
+        .lengths = lengths,
+        .lengths_len = 3,
+        .code_name = "CVV",
+        .code_size = 3,
+    };
+
+    CHECK(cct_pool_insert_card_type(pool, &spec) == CCT_OK);
+    CHECK(cct_pool_detect(pool, "4111111111111111", matches, 2, &count) == CCT_OK);
+    CHECK(count == 1);
+    CHECK(strcmp(matches[0].nice_type, "Visa Custom") == 0);
+    CHECK(matches[0].gaps_len == 0);
+
+    cct_pool_free(pool);
+    return 0;
+}
+
+int main(void) {
+    int failed = 0;
+
+    failed |= test_detect();
+    failed |= test_buffer_too_small();
+    failed |= test_detect_alloc();
+    failed |= test_errors();
+    failed |= test_custom_card_type();
+    failed |= test_replace_built_in_card_type();
+    failed |= test_long_range_bounds();
+
+    return failed;
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    regions, CardBrand, CardRange, CreditCardPool, CreditCardType, FundingType, RangeKind,
+    StaticCardPool, DEFAULT_CARD_TYPES,
+};
+
+#[test]
+fn test_default_funding_types() {
+    let pool = CreditCardPool::new();
+
+    let numbers = [
+        ("378282246310005", FundingType::Charge),
+        ("371449635398431", FundingType::Charge),
+        ("6304000000000000", FundingType::Debit),
+        ("5018000000000009", FundingType::Debit),
+        ("4026000000000002", FundingType::Debit),
+        ("4175000000000001", FundingType::Debit),
+        ("4917300000000008", FundingType::Debit),
+        ("4571000000000001", FundingType::Debit),
+        ("4111111111111111", FundingType::Unknown),
+        ("5555555555554444", FundingType::Unknown),
+        ("6011111111111117", FundingType::Unknown),
+    ];
+    for (number, funding) in numbers {
+        assert_eq!(
+            pool.funding_type(number).unwrap(),
+            funding,
+            "Failed for {}",
+            number
+        );
+        assert_eq!(
+            pool.get_card_matches(number).unwrap()[0].funding,
+            funding,
+            "Failed for {}",
+            number
+        );
+    }
+
+    // Visa Electron ranges are not known until the number covers them.
+    assert_eq!(pool.funding_type("4175").unwrap(), FundingType::Unknown);
+    assert_eq!(pool.funding_type("41750").unwrap(), FundingType::Unknown);
+    assert_eq!(pool.funding_type("417500").unwrap(), FundingType::Debit);
+
+    assert!(pool.funding_type("4026a").is_err());
+}
+
+#[test]
+fn test_funding_type_fallback() {
+    let pool = CreditCardPool::new();
+
+    // No card type matches.
+    assert_eq!(pool.funding_type("7").unwrap(), FundingType::Unknown);
+    // Maestro cards are debit, but the funding of the other matching card types is not known.
+    assert_eq!(pool.funding_type("6").unwrap(), FundingType::Unknown);
+    // Every matching card type agrees.
+    assert_eq!(pool.funding_type("34").unwrap(), FundingType::Charge);
+
+    const PREPAID: &[CardRange] = &[CardRange::new(&["2221"]).with_funding(FundingType::Prepaid)];
+    let mut pool = CreditCardPool::new_empty();
+    pool.insert_card_type(CreditCardType {
+        nice_type: "Gift card",
+        type_: CardBrand::Custom("gift-card"),
+        patterns: &[&["2221"]],
+        ranges: PREPAID,
+        ..DEFAULT_CARD_TYPES[0].clone()
+    });
+    assert_eq!(
+        pool.funding_type("2221000000000000").unwrap(),
+        FundingType::Prepaid
+    );
+
+    let static_pool = StaticCardPool::new();
+    assert_eq!(
+        static_pool.funding_type("378282246310005").unwrap(),
+        FundingType::Charge
+    );
+}
+
+#[test]
+fn test_regional_funding_types() {
+    let mut pool = CreditCardPool::new();
+    pool.extend(regions::ENABLED.iter().cloned());
+
+    #[cfg(feature = "region-eu")]
+    assert_eq!(
+        pool.funding_type("6703000000000000").unwrap(),
+        FundingType::Debit
+    );
+    #[cfg(feature = "region-nordics")]
+    assert_eq!(
+        pool.funding_type("5019000000000000").unwrap(),
+        FundingType::Debit
+    );
+}
+
+#[test]
+fn test_overlapping_funding_ranges() {
+    // A debit BIN range of an issuer, nested in the Elo range co-badged with Discover.
+    const RANGES: &[CardRange] = &[
+        CardRange::new(&["650405", "650439"]).with_funding(FundingType::Debit),
+        CardRange::new(&["6504051"]).with_kind(RangeKind::NetworkToken),
+    ];
+    let elo = DEFAULT_CARD_TYPES
+        .iter()
+        .find(|card_type| card_type.type_ == CardBrand::Elo)
+        .unwrap();
+    let mut pool = CreditCardPool::new();
+    pool.insert_card_type(CreditCardType {
+        ranges: [elo.ranges, RANGES].concat().leak(),
+        ..elo.clone()
+    });
+
+    let elo_match = |card_number| {
+        pool.get_card_matches(card_number)
+            .unwrap()
+            .into_iter()
+            .find(|card_match| card_match.primary() == CardBrand::Elo)
+            .unwrap()
+    };
+
+    let card_match = elo_match("6504050000000000");
+    assert_eq!(card_match.co_badged, [CardBrand::Discover]);
+    assert_eq!(card_match.funding, FundingType::Debit);
+    assert_eq!(card_match.kind, RangeKind::Regular);
+
+    let card_match = elo_match("6504051000000000");
+    assert_eq!(card_match.co_badged, [CardBrand::Discover]);
+    assert_eq!(card_match.funding, FundingType::Debit);
+    assert_eq!(card_match.kind, RangeKind::NetworkToken);
+
+    // Outside of the debit range, only the co-badging is known.
+    let card_match = elo_match("6504850000000000");
+    assert_eq!(card_match.co_badged, [CardBrand::Discover]);
+    assert_eq!(card_match.funding, FundingType::Unknown);
+}
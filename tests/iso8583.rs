@@ -0,0 +1,140 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    iso8583::{decode_pan, decode_track2, Encoding},
+    CardBrand, CardTypeError, CreditCardPool,
+};
+
+#[test]
+fn test_decode_pan() {
+    let field_tests = [
+        (
+            &b"164111111111111111rest"[..],
+            Encoding::Ascii,
+            "4111111111111111",
+        ),
+        (b"15378282246310005rest", Encoding::Ascii, "378282246310005"),
+        (
+            &[
+                0x16, 0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, b'r', b'e', b's', b't',
+            ],
+            Encoding::Bcd,
+            "4111111111111111",
+        ),
+        (
+            &[
+                0x15, 0x37, 0x82, 0x82, 0x24, 0x63, 0x10, 0x00, 0x5F, b'r', b'e', b's', b't',
+            ],
+            Encoding::Bcd,
+            "378282246310005",
+        ),
+        (
+            &[
+                0x15, 0x37, 0x82, 0x82, 0x24, 0x63, 0x10, 0x00, 0x50, b'r', b'e', b's', b't',
+            ],
+            Encoding::Bcd,
+            "378282246310005",
+        ),
+        (
+            &[
+                0x15, 0x03, 0x78, 0x28, 0x22, 0x46, 0x31, 0x00, 0x05, b'r', b'e', b's', b't',
+            ],
+            Encoding::BcdRightJustified,
+            "378282246310005",
+        ),
+    ];
+
+    let pool = CreditCardPool::new();
+
+    for (field, encoding, expected) in field_tests {
+        let (pan, rest) = decode_pan(field, encoding).unwrap();
+        assert_eq!(pan.expose(), expected, "Failed for {:02X?}", field);
+        assert_eq!(rest, b"rest", "Failed for {:02X?}", field);
+        assert!(!pool.get_credit_card_type(&pan).unwrap().is_empty());
+    }
+}
+
+#[test]
+fn test_decode_track2() {
+    let pool = CreditCardPool::new();
+
+    let (track2, rest) = decode_track2(b"245555555555554444=2512201", Encoding::Ascii).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(track2.pan.expose(), "5555555555554444");
+    assert_eq!(track2.service_code.unwrap().to_string(), "201");
+    assert_eq!(
+        track2.card_types(&pool).unwrap()[0].type_,
+        CardBrand::Mastercard
+    );
+
+    // 4111111111111111D2512101123, 27 characters padded with `F`.
+    let field = [
+        0x27, 0x41, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0xD2, 0x51, 0x21, 0x01, 0x12, 0x3F,
+        0x00,
+    ];
+    let (track2, rest) = decode_track2(&field, Encoding::Bcd).unwrap();
+    assert_eq!(rest, [0x00]);
+    assert_eq!(track2.pan.expose(), "4111111111111111");
+    assert_eq!(track2.discretionary_data, "123");
+    assert_eq!(track2.card_types(&pool).unwrap()[0].type_, CardBrand::Visa);
+
+    let field = [
+        0x27, 0x04, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1D, 0x25, 0x12, 0x10, 0x11, 0x23,
+    ];
+    let (track2, _) = decode_track2(&field, Encoding::BcdRightJustified).unwrap();
+    assert_eq!(track2.pan.expose(), "4111111111111111");
+    assert_eq!(track2.expiry.unwrap().to_string(), "12/25");
+}
+
+#[test]
+fn test_invalid_fields() {
+    let invalid = [
+        (&b"1"[..], Encoding::Ascii, "truncated field"),
+        (b"16411111111111111", Encoding::Ascii, "truncated field"),
+        (b"1a4111", Encoding::Ascii, "invalid length"),
+        (
+            b"204111111111111111111",
+            Encoding::Ascii,
+            "length over the maximum",
+        ),
+        (&[0x1A, 0x41], Encoding::Bcd, "invalid length"),
+        (&[], Encoding::Bcd, "truncated field"),
+        (&[0x16, 0x41, 0x11], Encoding::Bcd, "truncated field"),
+        (&[0x03, 0x41, 0x1A], Encoding::Bcd, "invalid padding"),
+        (
+            &[0x03, 0xF4, 0x11],
+            Encoding::BcdRightJustified,
+            "invalid padding",
+        ),
+        (&[0x04, 0x41, 0x1B], Encoding::Bcd, "invalid character"),
+        (
+            &[b'0', b'2', 0xC3, 0x28],
+            Encoding::Ascii,
+            "invalid character",
+        ),
+    ];
+    for (field, encoding, reason) in invalid {
+        assert!(
+            matches!(decode_pan(field, encoding), Err(CardTypeError::InvalidIso8583Field(error)) if error == reason),
+            "Failed for {:02X?}",
+            field
+        );
+    }
+
+    assert!(matches!(
+        decode_pan(b"044a11", Encoding::Ascii),
+        Err(CardTypeError::InvalidCardNumber)
+    ));
+    assert!(matches!(
+        decode_pan(&[0x04, 0x41, 0xD1], Encoding::Bcd),
+        Err(CardTypeError::InvalidCardNumber)
+    ));
+    assert!(matches!(
+        decode_track2(b"16411111111111111", Encoding::Ascii),
+        Err(CardTypeError::InvalidIso8583Field(_))
+    ));
+    assert!(matches!(
+        decode_track2(b"164111111111111111", Encoding::Ascii),
+        Err(CardTypeError::InvalidTrackData(_))
+    ));
+}
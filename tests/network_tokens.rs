@@ -0,0 +1,100 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    CardBrand, CardRange, CreditCardPool, CreditCardType, FundingType, RangeKind, StaticCardPool,
+    DEFAULT_CARD_TYPES,
+};
+
+/// Token and virtual ranges as they would come from a BIN table, nested in a regular range.
+const RANGES: &[CardRange] = &[
+    CardRange::new(&["489500", "489599"]).with_kind(RangeKind::NetworkToken),
+    CardRange::new(&["4895371"]).with_kind(RangeKind::Virtual),
+    CardRange::new(&["4895"]),
+    // A token range in the Visa Electron range 417500.
+    CardRange::new(&["4175001"]).with_kind(RangeKind::NetworkToken),
+];
+
+fn visa_with_ranges() -> CreditCardType {
+    let visa = DEFAULT_CARD_TYPES
+        .iter()
+        .find(|card_type| card_type.type_ == CardBrand::Visa)
+        .unwrap();
+
+    // The built-in ranges are kept.
+    CreditCardType {
+        ranges: [visa.ranges, RANGES].concat().leak(),
+        ..visa.clone()
+    }
+}
+
+#[test]
+fn test_network_tokens() {
+    let mut pool = CreditCardPool::new();
+    pool.insert_card_type(visa_with_ranges());
+
+    let card_match = &pool.get_card_matches("4895370000000001").unwrap()[0];
+    assert_eq!(card_match.primary(), CardBrand::Visa);
+    assert_eq!(card_match.kind, RangeKind::NetworkToken);
+    assert!(card_match.is_network_token());
+    assert!(!card_match.is_virtual());
+
+    // The most specific range wins.
+    let card_match = &pool.get_card_matches("4895371000000000").unwrap()[0];
+    assert_eq!(card_match.kind, RangeKind::Virtual);
+    assert!(card_match.is_virtual());
+
+    for card_number in ["4111111111111111", "4895"] {
+        let card_match = &pool.get_card_matches(card_number).unwrap()[0];
+        assert_eq!(
+            card_match.kind,
+            RangeKind::Regular,
+            "Failed for {}",
+            card_number
+        );
+        assert!(!card_match.is_network_token());
+    }
+
+    let visa = visa_with_ranges();
+    assert_eq!(
+        visa.kind("4895999999999999").unwrap(),
+        RangeKind::NetworkToken
+    );
+    assert_eq!(visa.kind("4895").unwrap(), RangeKind::Regular);
+    assert!(visa.kind("48950a0000000000").is_err());
+    assert_eq!(RangeKind::NetworkToken.to_string(), "network-token");
+}
+
+#[test]
+fn test_default_ranges_are_regular() {
+    let pool = StaticCardPool::new();
+
+    for card_number in ["4571000000000001", "6504050000000000", "378282246310005"] {
+        let card_match = pool.get_card_matches(card_number).unwrap().next().unwrap();
+        assert_eq!(
+            card_match.kind,
+            RangeKind::Regular,
+            "Failed for {}",
+            card_number
+        );
+    }
+}
+
+#[test]
+fn test_network_tokens_in_debit_ranges() {
+    let mut pool = CreditCardPool::new();
+    pool.insert_card_type(visa_with_ranges());
+
+    // A token range only declares the kind, the funding type comes from the Visa Electron range around it.
+    let card_match = &pool.get_card_matches("4175001000000000").unwrap()[0];
+    assert_eq!(card_match.kind, RangeKind::NetworkToken);
+    assert_eq!(card_match.funding, FundingType::Debit);
+
+    let card_match = &pool.get_card_matches("4175002000000000").unwrap()[0];
+    assert_eq!(card_match.kind, RangeKind::Regular);
+    assert_eq!(card_match.funding, FundingType::Debit);
+
+    // The Visa/Dankort range is kept too.
+    let card_match = &pool.get_card_matches("4571000000000001").unwrap()[0];
+    assert_eq!(card_match.co_badged, [CardBrand::Dankort]);
+    assert_eq!(card_match.funding, FundingType::Debit);
+}
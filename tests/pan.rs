@@ -0,0 +1,58 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{mask_card_number, CardBrand, CardTypeError, CreditCardPool, Pan};
+
+#[test]
+fn test_pan_validation() {
+    let valid = [
+        ("4111111111111111", "4111111111111111"),
+        ("4111 1111 1111 1111", "4111111111111111"),
+        ("3782-822463-10005", "378282246310005"),
+        ("4", "4"),
+        ("4111111111111111110", "4111111111111111110"),
+    ];
+    for (input, digits) in valid {
+        let pan: Pan = input.parse().unwrap();
+        assert_eq!(pan.expose(), digits, "Failed for {}", input);
+
+        let pan = Pan::new(input.to_string()).unwrap();
+        assert_eq!(pan.expose(), digits, "Failed for {}", input);
+    }
+
+    let invalid = ["", " - ", "4111a111", "41111111111111111100", "４１１１"];
+    for input in invalid {
+        assert!(
+            matches!(input.parse::<Pan>(), Err(CardTypeError::InvalidCardNumber)),
+            "Failed for {}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_pan_is_redacted() {
+    let pan = Pan::try_from("5555555555554444".to_string()).unwrap();
+
+    assert_eq!(format!("{pan:?}"), "Pan(************4444)");
+    assert_eq!(format!("{pan}"), "************4444");
+    assert_eq!(pan.last_four(), "4444");
+    assert_eq!(pan.len(), 16);
+
+    let short: Pan = "411".parse().unwrap();
+    assert_eq!(format!("{short:?}"), "Pan(411)");
+    assert_eq!(short.last_four(), "411");
+}
+
+#[test]
+fn test_pan_is_accepted_by_helpers() {
+    let pool = CreditCardPool::new();
+    let pan: Pan = "3782 822463 10005".parse().unwrap();
+
+    let card_types = pool.get_credit_card_type(&pan).unwrap();
+    assert_eq!(card_types.len(), 1);
+    assert_eq!(card_types[0].type_, CardBrand::AmericanExpress);
+
+    assert!(pool.validate_card_number(&pan).unwrap().is_valid());
+    assert_eq!(card_types[0].format_card_number(&pan), "3782 822463 10005");
+    assert_eq!(mask_card_number(&pan), "***********0005");
+}
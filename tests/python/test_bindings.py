@@ -0,0 +1,91 @@
+"""Tests for the Python bindings, run with `maturin develop && python -m unittest discover tests/python`."""
+
+import threading
+import unittest
+
+from credit_card_types import CreditCardPool, luhn_check, mask_card_number
+
+
+class TestDetection(unittest.TestCase):
+    def setUp(self):
+        self.pool = CreditCardPool()
+
+    def test_get_credit_card_type(self):
+        [card_type] = self.pool.get_credit_card_type("3782 822463 10005")
+
+        self.assertEqual(card_type.type, "american-express")
+        self.assertEqual(card_type.nice_type, "American Express")
+        self.assertEqual(card_type.gaps, [4, 10])
+        self.assertEqual(card_type.lengths, [15])
+        self.assertEqual(card_type.code.name, "CID")
+        self.assertEqual(card_type.code.size, 4)
+        self.assertEqual(card_type.code.mask, "####")
+        self.assertEqual(card_type.max_length, 15)
+        self.assertEqual(card_type.input_mask, "#### ###### #####")
+        self.assertEqual(card_type.max_input_length, 17)
+        self.assertEqual(card_type.match_strength, 2)
+        self.assertEqual(card_type.format_card_number("378282246310005"), "3782 822463 10005")
+
+        self.assertEqual(len(self.pool.get_credit_card_type("3")), 3)
+
+    def test_invalid_number_raises(self):
+        with self.assertRaises(ValueError):
+            self.pool.get_credit_card_type("4111 abcd")
+        with self.assertRaises(ValueError):
+            self.pool.validate_card_number("4111 abcd")
+
+    def test_detect_many(self):
+        results = self.pool.detect_many(["4111111111111111", "3", "not a number"])
+
+        self.assertEqual([card_type.type for card_type in results[0]], ["visa"])
+        self.assertEqual(len(results[1]), 3)
+        self.assertIsNone(results[2])
+
+    def test_detect_brands(self):
+        brands = self.pool.detect_brands(["4111-1111-1111-1111", "3", "?", "5555555555554444"])
+
+        self.assertEqual(brands, ["visa", None, None, "mastercard"])
+
+    def test_validation(self):
+        validation = self.pool.validate_card_number("4111 1111 1111 1111")
+
+        self.assertTrue(validation.is_valid)
+        self.assertTrue(validation.luhn_valid)
+        self.assertTrue(validation.length_valid)
+        self.assertEqual(validation.card_type.type, "visa")
+        self.assertTrue(validation)
+
+        self.assertEqual(
+            self.pool.validate_many(["4111111111111111", "4111111111111112", "?"]),
+            [True, False, False],
+        )
+
+    def test_batch_calls_from_threads(self):
+        numbers = ["4111111111111111", "378282246310005"] * 10_000
+        results = []
+
+        def detect():
+            results.append(self.pool.detect_brands(numbers))
+
+        threads = [threading.Thread(target=detect) for _ in range(4)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        self.assertEqual(len(results), 4)
+        for brands in results:
+            self.assertEqual(brands[:2], ["visa", "american-express"])
+
+
+class TestHelpers(unittest.TestCase):
+    def test_mask_card_number(self):
+        self.assertEqual(mask_card_number("4111-1111-1111-1111"), "****-****-****-1111")
+
+    def test_luhn_check(self):
+        self.assertTrue(luhn_check("4111 1111 1111 1111"))
+        self.assertFalse(luhn_check("4111 1111 1111 1112"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,151 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{luhn_check_digit, regions, CardBrand, CreditCardPool};
+
+/// Completes `prefix` with zeros and a Luhn check digit to a number of `length` digits.
+#[allow(dead_code)]
+fn card_number(prefix: &str, length: usize) -> String {
+    let mut number = format!("{prefix:0<width$}", width = length - 1);
+    number.push(char::from(b'0' + luhn_check_digit(&number).unwrap()));
+    number
+}
+
+#[allow(dead_code)]
+fn pool_with_regions() -> CreditCardPool {
+    let mut pool = CreditCardPool::new();
+    pool.extend(regions::ENABLED.iter().cloned());
+    pool
+}
+
+#[allow(dead_code)]
+fn assert_detected(pool: &CreditCardPool, prefix: &str, length: usize, brand: CardBrand) {
+    let number = card_number(prefix, length);
+    let validation = pool.validate_card_number(&number).unwrap();
+
+    assert_eq!(
+        validation
+            .card_type
+            .as_ref()
+            .map(|card_type| card_type.type_),
+        Some(brand),
+        "Failed for {}",
+        number
+    );
+    assert!(validation.is_valid(), "Failed for {}", number);
+}
+
+#[test]
+fn test_enabled_packs_are_sorted() {
+    assert!(regions::ENABLED
+        .windows(2)
+        .all(|pair| pair[0].type_ < pair[1].type_));
+
+    let pool = pool_with_regions();
+    assert!(pool
+        .card_types()
+        .windows(2)
+        .all(|pair| pair[0].type_ < pair[1].type_));
+}
+
+#[test]
+#[cfg(feature = "region-india")]
+fn test_rupay() {
+    let pool = pool_with_regions();
+
+    for prefix in [
+        "508500", "508999", "607000", "608100", "652150", "653149", "8172", "8202",
+    ] {
+        assert_detected(&pool, prefix, 16, CardBrand::RuPay);
+    }
+
+    // Neighbouring ranges keep their card types.
+    assert_detected(&pool, "508499", 16, CardBrand::Maestro);
+    assert_detected(&pool, "653150", 16, CardBrand::Discover);
+    assert_detected(&pool, "6011", 16, CardBrand::Discover);
+
+    assert_eq!(regions::INDIA, [regions::RUPAY]);
+    assert_eq!(regions::RUPAY.code.name, "CVV");
+}
+
+#[test]
+#[cfg(feature = "region-turkey")]
+fn test_troy() {
+    let pool = pool_with_regions();
+
+    assert_detected(&pool, "979200", 16, CardBrand::Troy);
+    assert_detected(&pool, "979289", 16, CardBrand::Troy);
+    assert!(pool
+        .get_credit_card_type(card_number("979290", 16))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+#[cfg(feature = "region-africa")]
+fn test_verve() {
+    let pool = pool_with_regions();
+
+    for length in [16, 18, 19] {
+        assert_detected(&pool, "506099", length, CardBrand::Verve);
+        assert_detected(&pool, "650027", length, CardBrand::Verve);
+    }
+    assert_detected(&pool, "506199", 16, CardBrand::Maestro);
+}
+
+#[test]
+#[cfg(feature = "region-nordics")]
+fn test_dankort() {
+    let pool = pool_with_regions();
+
+    assert_detected(&pool, "5019", 16, CardBrand::Dankort);
+    assert_detected(&pool, "5020", 16, CardBrand::Maestro);
+
+    let card_match = &pool.get_card_matches(card_number("4571", 16)).unwrap()[0];
+    assert_eq!(
+        card_match.networks().collect::<Vec<_>>(),
+        [CardBrand::Visa, CardBrand::Dankort]
+    );
+}
+
+#[test]
+#[cfg(feature = "region-eu")]
+fn test_bancontact() {
+    let pool = pool_with_regions();
+
+    for length in [16, 17, 18, 19] {
+        assert_detected(&pool, "6703", length, CardBrand::Bancontact);
+    }
+
+    let card_match = &pool.get_card_matches(card_number("6703", 17)).unwrap()[0];
+    assert_eq!(card_match.co_badged, [CardBrand::Maestro]);
+    assert_eq!(card_match.card_type.code.size, 0);
+}
+
+#[test]
+#[cfg(feature = "region-vietnam")]
+fn test_napas() {
+    let pool = pool_with_regions();
+
+    assert_detected(&pool, "9704", 16, CardBrand::Napas);
+    assert_detected(&pool, "9704", 19, CardBrand::Napas);
+}
+
+#[test]
+fn test_default_pool_has_no_region_card_types() {
+    let pool = CreditCardPool::new();
+
+    for brand in [
+        CardBrand::RuPay,
+        CardBrand::Troy,
+        CardBrand::Verve,
+        CardBrand::Dankort,
+        CardBrand::Bancontact,
+        CardBrand::Napas,
+    ] {
+        assert!(pool
+            .card_types()
+            .iter()
+            .all(|card_type| card_type.type_ != brand));
+        assert_eq!(brand.to_string().parse::<CardBrand>().unwrap(), brand);
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "std")]
+
+use credit_card_types::{CardBrand, CreditCardPool, PanScanner};
+
+#[test]
+fn test_find_pans() {
+    let pool = CreditCardPool::new();
+    let scanner = PanScanner::new(&pool);
+
+    let text = "visa=4111111111111111 amex: 3782 822463 10005; mc 5555-5555-5555-4444.";
+    let pans = scanner.find_pans(text);
+
+    let found = pans
+        .iter()
+        .map(|pan| (&text[pan.start..pan.end], pan.card_type.type_))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        [
+            ("4111111111111111", CardBrand::Visa),
+            ("3782 822463 10005", CardBrand::AmericanExpress),
+            ("5555-5555-5555-4444", CardBrand::Mastercard),
+        ]
+    );
+}
+
+#[test]
+fn test_find_pans_ignores_false_positives() {
+    let pool = CreditCardPool::new();
+    let scanner = PanScanner::new(&pool);
+
+    let card_tests = [
+        // Luhn fails.
+        "card 4111111111111112",
+        // Part of a longer number or word.
+        "id 41111111111111110000",
+        "token a4111111111111111",
+        "token 4111111111111111b",
+        // Too short, or no card type.
+        "phone 411111111116",
+        "ts 1700000000000",
+        // Double separators are not a card number format.
+        "4111  1111  1111  1111",
+    ];
+
+    for text in card_tests {
+        assert!(scanner.find_pans(text).is_empty(), "Failed for {}", text);
+    }
+}
+
+#[test]
+fn test_find_pans_next_to_other_numbers() {
+    let pool = CreditCardPool::new();
+    let scanner = PanScanner::new(&pool);
+
+    assert_eq!(
+        scanner.redact("ref 12 4111 1111 1111 1111 99"),
+        "ref 12 **** **** **** 1111 99"
+    );
+    assert_eq!(
+        scanner.redact("4111111111111111 5555555555554444"),
+        "************1111 ************4444"
+    );
+}
+
+#[test]
+fn test_redact_stream() {
+    let pool = CreditCardPool::new();
+    let scanner = PanScanner::new(&pool);
+
+    let input = "first 4111111111111111\nnothing here\r\nlast 6011-0009-9013-9424 ok";
+    let mut output = Vec::new();
+    let redacted = scanner
+        .redact_stream(input.as_bytes(), &mut output)
+        .unwrap();
+
+    assert_eq!(redacted, 2);
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "first ************1111\nnothing here\r\nlast ****-****-****-9424 ok"
+    );
+}
+
+#[test]
+fn test_redact_stream_long_lines() {
+    let pool = CreditCardPool::new();
+    let scanner = PanScanner::new(&pool);
+
+    // Lines much longer than what is held in memory, with card numbers everywhere.
+    let line = "card 4111 1111 1111 1111, ünïcödé 5555555555554444; ".repeat(10_000);
+    let input = format!("{line}\nshort 378282246310005\n{line}");
+    let mut output = Vec::new();
+    let redacted = scanner
+        .redact_stream(input.as_bytes(), &mut output)
+        .unwrap();
+
+    assert_eq!(redacted, 40_001);
+    assert_eq!(String::from_utf8(output).unwrap(), scanner.redact(&input));
+
+    let result = scanner.redact_stream(&b"4111111111111111 \xff\n"[..], Vec::new());
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{
+    Authorization, CardTypeError, Interchange, PinRequirement, ServiceCode, Services, Track2,
+};
+
+#[test]
+fn test_service_codes() {
+    let service_code_tests = [
+        (
+            "101",
+            Interchange::International,
+            false,
+            Authorization::Normal,
+            Services::NoRestrictions,
+            PinRequirement::None,
+        ),
+        (
+            "201",
+            Interchange::International,
+            true,
+            Authorization::Normal,
+            Services::NoRestrictions,
+            PinRequirement::None,
+        ),
+        (
+            "120",
+            Interchange::International,
+            false,
+            Authorization::Online,
+            Services::NoRestrictions,
+            PinRequirement::Required,
+        ),
+        (
+            "523",
+            Interchange::National,
+            false,
+            Authorization::Online,
+            Services::AtmOnly,
+            PinRequirement::Required,
+        ),
+        (
+            "606",
+            Interchange::National,
+            true,
+            Authorization::Normal,
+            Services::NoRestrictions,
+            PinRequirement::WhereFeasible,
+        ),
+        (
+            "742",
+            Interchange::Private,
+            false,
+            Authorization::OnlineUnlessBilateral,
+            Services::GoodsAndServices,
+            PinRequirement::None,
+        ),
+        (
+            "904",
+            Interchange::Test,
+            false,
+            Authorization::Normal,
+            Services::CashOnly,
+            PinRequirement::None,
+        ),
+        (
+            "318",
+            Interchange::Reserved,
+            false,
+            Authorization::Reserved,
+            Services::Reserved,
+            PinRequirement::Reserved,
+        ),
+    ];
+
+    for (input, interchange, has_chip, authorization, services, pin) in service_code_tests {
+        let service_code: ServiceCode = input.parse().unwrap();
+        assert_eq!(service_code.to_string(), input);
+        assert_eq!(
+            service_code.interchange(),
+            interchange,
+            "Failed for {}",
+            input
+        );
+        assert_eq!(service_code.has_chip(), has_chip, "Failed for {}", input);
+        assert_eq!(
+            service_code.authorization(),
+            authorization,
+            "Failed for {}",
+            input
+        );
+        assert_eq!(service_code.services(), services, "Failed for {}", input);
+        assert_eq!(service_code.pin(), pin, "Failed for {}", input);
+    }
+
+    let service_code: ServiceCode = "523".parse().unwrap();
+    assert!(!service_code.is_international());
+    assert!(service_code.requires_online_authorization());
+    assert!(service_code.requires_pin());
+    assert!(service_code.is_atm_only());
+
+    let service_code = ServiceCode::from_digits([1, 0, 1]).unwrap();
+    assert_eq!(service_code.digits(), [1, 0, 1]);
+    assert!(service_code.is_international());
+    assert!(!service_code.requires_online_authorization());
+    assert!(!service_code.requires_pin());
+    assert!(!service_code.is_atm_only());
+}
+
+#[test]
+fn test_invalid_service_codes() {
+    for input in ["", "10", "1011", "1a1", "+01", "１０１"] {
+        assert!(
+            matches!(
+                input.parse::<ServiceCode>(),
+                Err(CardTypeError::InvalidTrackData(_))
+            ),
+            "Failed for {}",
+            input
+        );
+    }
+    assert!(ServiceCode::from_digits([1, 0, 10]).is_err());
+}
+
+#[test]
+fn test_service_code_of_track() {
+    let track: Track2 = ";5555555555554444=2512222?".parse().unwrap();
+    let service_code = track.service_code.unwrap();
+
+    assert!(service_code.has_chip());
+    assert!(service_code.requires_online_authorization());
+    assert_eq!(service_code.services(), Services::GoodsAndServices);
+}
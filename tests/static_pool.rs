@@ -0,0 +1,67 @@
+#[cfg(feature = "alloc")]
+use credit_card_types::CreditCardPool;
+use credit_card_types::{CardBrand, Code, CreditCardType, StaticCardPool};
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_static_pool_matches_credit_card_pool() {
+    let card_tests = [
+        "",
+        "4",
+        "411",
+        "4111111111111111",
+        "431274",
+        "2",
+        "3",
+        "50",
+        "6",
+        "62",
+        "6277809",
+        "637095",
+        "606282",
+        "65502",
+        "2200000000000000",
+        "7",
+        "0",
+    ];
+
+    let pool = CreditCardPool::new();
+    let static_pool = StaticCardPool::new();
+
+    for card_number in card_tests {
+        let expected = pool.get_credit_card_type(card_number).unwrap();
+        let actual = static_pool
+            .get_credit_card_type(card_number)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected, "Failed for {}", card_number);
+    }
+
+    assert_eq!(static_pool.card_types(), pool.get_all_card_types());
+}
+
+#[test]
+fn test_static_pool_from_card_types() {
+    static CARD_TYPES: [CreditCardType; 1] = [CreditCardType {
+        nice_type: "Custom",
+        type_: CardBrand::Custom("custom"),
+        patterns: &[&["1234"], &["5000", "5999"]],
+        validity: &[],
+        ranges: &[],
+        gaps: &[4, 8, 12],
+        lengths: &[16],
+        code: Code {
+            name: "CVC",
+            size: 3,
+        },
+        match_strength: 0,
+    }];
+    const POOL: StaticCardPool = StaticCardPool::from_card_types(&CARD_TYPES);
+
+    let card_type = POOL.get_credit_card_type("5500").unwrap().next().unwrap();
+    assert_eq!(card_type.type_, CardBrand::Custom("custom"));
+    assert_eq!(card_type.match_strength, 4);
+
+    assert_eq!(POOL.get_credit_card_type("4").unwrap().count(), 0);
+    assert!(POOL.get_credit_card_type("5a").is_err());
+}
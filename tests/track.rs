@@ -0,0 +1,151 @@
+#![cfg(feature = "alloc")]
+
+use credit_card_types::{CardBrand, CardTypeError, CreditCardPool, Expiry, Track1, Track2};
+
+#[test]
+fn test_track1() {
+    let tracks = [
+        "%B4111111111111111^DOE/JOHN^2512101123?",
+        "%B4111111111111111^DOE/JOHN^2512101123?;",
+        "B4111111111111111^DOE/JOHN                  ^2512101123",
+    ];
+
+    let pool = CreditCardPool::new();
+
+    for input in tracks {
+        let track: Track1 = input.parse().unwrap();
+        assert_eq!(
+            track.pan.expose(),
+            "4111111111111111",
+            "Failed for {}",
+            input
+        );
+        assert_eq!(track.name, "DOE/JOHN", "Failed for {}", input);
+        assert_eq!(
+            track.expiry,
+            Some(Expiry {
+                year: 25,
+                month: 12
+            })
+        );
+        assert_eq!(track.service_code, "101".parse().ok());
+        assert_eq!(track.discretionary_data, "123");
+        assert_eq!(track.card_types(&pool).unwrap()[0].type_, CardBrand::Visa);
+    }
+
+    let track = Track1::parse("%B5555555555554444^SMITH/ANN^^^0000?").unwrap();
+    assert_eq!(track.expiry, None);
+    assert_eq!(track.service_code, None);
+    assert_eq!(track.discretionary_data, "0000");
+    assert_eq!(
+        track.card_types(&pool).unwrap()[0].type_,
+        CardBrand::Mastercard
+    );
+}
+
+#[test]
+fn test_track2() {
+    let tracks = [
+        ";378282246310005=2512101123?",
+        ";378282246310005=2512101123?>",
+        "378282246310005=2512101123",
+    ];
+
+    let pool = CreditCardPool::new();
+
+    for input in tracks {
+        let track: Track2 = input.parse().unwrap();
+        assert_eq!(
+            track.pan.expose(),
+            "378282246310005",
+            "Failed for {}",
+            input
+        );
+        assert_eq!(track.expiry.unwrap().to_string(), "12/25");
+        assert_eq!(track.service_code, "101".parse().ok());
+        assert_eq!(track.discretionary_data, "123");
+        assert_eq!(
+            track.card_types(&pool).unwrap()[0].type_,
+            CardBrand::AmericanExpress
+        );
+    }
+
+    let track = Track2::parse(";4111111111111111===?").unwrap();
+    assert_eq!(track.expiry, None);
+    assert_eq!(track.service_code, None);
+    assert_eq!(track.discretionary_data, "");
+}
+
+#[test]
+fn test_invalid_tracks() {
+    let invalid_track1 = [
+        ("%B4111111111111111^DOE/JOHN^2512101123?A", "LRC mismatch"),
+        (
+            "B4111111111111111^DOE/JOHN^2512101123?;",
+            "LRC without start sentinel",
+        ),
+        (
+            "%B4111111111111111^DOE/JOHN^2512101123?;;",
+            "data after the end sentinel",
+        ),
+        (
+            "%A4111111111111111^DOE/JOHN^2512101123?",
+            "format code is not `B`",
+        ),
+        ("%B4111111111111111^DOE/JOHN?", "missing field separator"),
+        (
+            "%B4111111111111111^DOE/JOHN^2513101?",
+            "invalid expiry date",
+        ),
+        ("%B4111111111111111^DOE/JOHN^25?", "invalid expiry date"),
+        ("%B4111111111111111^DOE/JOHN^25121?", "invalid service code"),
+    ];
+    for (input, reason) in invalid_track1 {
+        assert!(
+            matches!(Track1::parse(input), Err(CardTypeError::InvalidTrackData(error)) if error == reason),
+            "Failed for {}",
+            input
+        );
+    }
+
+    let invalid_track2 = [
+        (";378282246310005=2512101123?0", "LRC mismatch"),
+        (";378282246310005", "missing field separator"),
+        (";378282246310005=2500101?", "invalid expiry date"),
+        (";378282246310005=25121A1?", "invalid service code"),
+        (";378282246310005=2512101A?", "invalid discretionary data"),
+    ];
+    for (input, reason) in invalid_track2 {
+        assert!(
+            matches!(Track2::parse(input), Err(CardTypeError::InvalidTrackData(error)) if error == reason),
+            "Failed for {}",
+            input
+        );
+    }
+
+    for input in [
+        ";=2512101?",
+        ";4111 1111=2512101?",
+        ";41111111111111111111=2512101?",
+    ] {
+        assert!(
+            matches!(Track2::parse(input), Err(CardTypeError::InvalidCardNumber)),
+            "Failed for {}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_debug_leaves_out_secrets() {
+    let track = Track1::parse("%B4111111111111111^DOE/JOHN^2512101987?").unwrap();
+    let debug = format!("{track:?}");
+    assert!(!debug.contains("4111111111111111"));
+    assert!(!debug.contains("987"));
+    assert!(debug.contains("1111"));
+
+    let track = Track2::parse(";4111111111111111=2512101987?").unwrap();
+    let debug = format!("{track:?}");
+    assert!(!debug.contains("4111111111111111"));
+    assert!(!debug.contains("987"));
+}
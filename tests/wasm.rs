@@ -0,0 +1,52 @@
+#![cfg(feature = "wasm")]
+
+use credit_card_types::wasm::{wasm_luhn_check, wasm_mask_card_number, WasmCreditCardPool};
+
+#[test]
+fn test_wasm_detection() {
+    let pool = WasmCreditCardPool::new();
+
+    let card_types = pool.get_credit_card_type("3782 822463 10005").unwrap();
+    assert_eq!(card_types.len(), 1);
+    assert_eq!(card_types[0].type_(), "american-express");
+    assert_eq!(card_types[0].nice_type(), "American Express");
+    assert_eq!(card_types[0].gaps(), [4, 10]);
+    assert_eq!(card_types[0].lengths(), [15]);
+    assert_eq!(card_types[0].code().name(), "CID");
+    assert_eq!(card_types[0].code().size(), 4);
+    assert_eq!(card_types[0].code().mask(), "####");
+    assert_eq!(card_types[0].max_length(), 15);
+    assert_eq!(card_types[0].input_mask(), "#### ###### #####");
+    assert_eq!(card_types[0].max_input_length(), 17);
+    assert_eq!(card_types[0].match_strength(), 2);
+
+    assert_eq!(pool.get_credit_card_type("3").unwrap().len(), 3);
+}
+
+#[test]
+fn test_wasm_validation_and_formatting() {
+    let pool = WasmCreditCardPool::new();
+
+    let validation = pool.validate_card_number("4111-1111-1111-1111").unwrap();
+    assert!(validation.is_valid());
+    assert!(validation.luhn_valid());
+    assert!(validation.length_valid());
+    assert_eq!(validation.card_type().unwrap().type_(), "visa");
+
+    assert!(!pool
+        .validate_card_number("4111111111111112")
+        .unwrap()
+        .is_valid());
+
+    assert_eq!(
+        pool.format_card_number("378282246310005").unwrap(),
+        "3782 822463 10005"
+    );
+    assert_eq!(pool.format_card_number("3").unwrap(), "3");
+    assert_eq!(
+        wasm_mask_card_number("4111111111111111"),
+        "************1111"
+    );
+    assert!(wasm_luhn_check("4111 1111 1111 1111"));
+    assert!(!wasm_luhn_check("4111a"));
+}